@@ -5,241 +5,345 @@ pub mod calculator_button;
 pub mod screen_settings;
 use screen_settings::{ROW_SPACE, COLUMN_SPACE};
 
-use std::char;
+use crate::engine;
+use crate::engine::{Environment, Notation, Number, Settings, Value};
+use crate::engine::environment::Outcome;
+use crate::engine::rational::{FractionStyle, Rational, RationalContext};
+use crate::engine::word::{Radix, Word, WordSize};
 
 use iced::executor;
-use iced::{Application, Command, Theme};
-use iced::widget::{column, container, row, text, vertical_space};
+use iced::{color, window, Alignment, Application, Command, Size, Theme};
+use iced::widget::{button, column, container, row, text, vertical_space, Column, Row};
 use iced::alignment;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Operator {
-    Add,
-    Sub,
-    Mul,
-    Div,
-}
-
-impl Operator {
-    pub fn value(&self) -> u16 {
-        match self {
-            Self::Add => 0,
-            Self::Sub => 0,
-            Self::Mul => 5,
-            Self::Div => 5,
-        }
-    }
-}
+pub use crate::engine::{Error, Operator, Span, Spanned, Token};
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
-pub enum Token {
-    Number(f32),
-    Operator(Operator),
-    Bracket(char),
+/// The keypads the calculator can show.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Standard,
+    /// Wrapping integers of a fixed word size, shown in several radixes.
+    Programmer,
 }
 
 pub struct Calculator {
     expression: String,
-    result: Option<String>
-}
-
-#[derive(Debug)]
-pub enum Error {
-    BadToken(char),
-    MismatchedParens,
+    result: Option<Rational>,
+    error: Option<Error>,
+    /// The variables and settings of the session.
+    environment: Environment<Rational>,
+    mode: Mode,
+    /// The last result in programmer mode.
+    word: Option<Word>,
+    /// The variables and word size of the programmer mode.
+    programmer: Environment<Word>,
 }
 
 impl Calculator {
 
-    pub fn parse<T: AsRef<str>>(expr: T) -> Result<Vec<Token>, Error> {
-        let expr = expr.as_ref();
-        let chars = expr.chars();
-        let mut tokens: Vec<Token> = Vec::new();
-        let mut parens = Vec::new(); 
-        let mut is_calculating_decimals: bool = false;
-        let mut decimal_place: f32 = 0.1;
-
-        for c in chars {
-            match c {
-                '0'..='9' => match tokens.last_mut() {
-                    Some(Token::Number(n)) => {
-                        if is_calculating_decimals {
-                            *n += ((c as i32) as f32 - 48.0) * decimal_place;
-                            decimal_place *= 0.1;
-                        } else {
-                            *n = *n * 10.0 + ((c as i32) as f32 - 48.0);
-                        }
-                    },
-                    _ => {
-                        let digit = (c as i32) as f32 - 48.0;
-                        tokens.push(Token::Number(digit));
-                    }
-                },
-                '.' => {
-                    is_calculating_decimals = true;
-                    decimal_place = 0.1;
-                },
-                '(' => {
-                    tokens.push(Token::Bracket('('));
-                    parens.push(c);
-                    is_calculating_decimals = false;
-                },
-                ')' => {
-                    tokens.push(Token::Bracket(')'));
-                    if let Some(p) = parens.pop() {
-                        if p != '(' {
-                            return Err(Error::MismatchedParens);
-                        }
-                    } else {
-                        return Err(Error::MismatchedParens);
-                    }
-                    is_calculating_decimals = false;
-                },
-                '+' => {
-                    tokens.push(Token::Operator(Operator::Add));
-                    is_calculating_decimals = false;
-                },
-                '-' => {
-                    tokens.push(Token::Operator(Operator::Sub));
-                    is_calculating_decimals = false;
-                },
-                '*' => {
-                    tokens.push(Token::Operator(Operator::Mul));
-                    is_calculating_decimals = false;
-                },
-                '/' => {
-                    tokens.push(Token::Operator(Operator::Div));
-                    is_calculating_decimals = false;
-                },
-                ' ' => is_calculating_decimals = false,
-                '\n' => is_calculating_decimals = false,
-                _ => return Err(Error::BadToken(c))
-            }
-        }
+    /// Tokenizes an expression. See `engine::tokenize`.
+    pub fn parse<T: AsRef<str>>(expr: T) -> Result<Vec<Spanned<Token>>, Error> {
+        engine::tokenize(expr.as_ref())
+    }
 
-        if parens.len() > 0 {
-            return Err(Error::MismatchedParens);
-        }
+    /// Converts tokens to reverse polish notation. See `engine::to_rpn`.
+    pub fn expression(tokens: Vec<Spanned<Token>>) -> Vec<Spanned<Token>> {
+        engine::to_rpn(tokens)
+    }
 
-        Ok(tokens)
+    /// Evaluates reverse polish tokens. See `engine::evaluate`.
+    pub fn evaluate(tokens: Vec<Spanned<Token>>) -> Result<f64, Error> {
+        engine::evaluate(tokens)
     }
 
-    pub fn expression(mut tokens: Vec<Token>) -> Vec<Token> {
-        tokens.reverse();
+    /// Evaluates an expression and formats the result. See `engine::resolve`.
+    pub fn resolve(expression: String) -> Result<String, Error> {
+        engine::resolve(expression)
+    }
 
-        let mut queue: Vec<Token> = Vec::new();
-        let mut stack: Vec<Token> = Vec::new();
+    pub fn count_char(char: char, expr: String) -> u32 {
+        let mut char_count: u32 = 0;
 
-        while let Some(token) = tokens.pop() {
-            match token {
-                Token::Number(_) => {
-                    queue.push(token);
-                },
-                Token::Operator(_) => {
-                    while !stack.is_empty() && stack[stack.len() - 1] != Token::Bracket('('){
-                        if let Some(Token::Operator(last_stack_operator)) = stack.last().cloned() {
-                            if let Token::Operator(current_operator) = token {
-                                if last_stack_operator.value() >= current_operator.value() {
-                                    queue.push(stack.pop().unwrap());
-                                } else {
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                    stack.push(token);
-                },
-                Token::Bracket('(') =>{
-                    stack.push(token);
-                },
-                Token::Bracket(')') => {
-                    while !stack.is_empty() && stack[stack.len() - 1] != Token::Bracket('(') {
-                        queue.push(stack.pop().unwrap());
-                    }
-                    stack.pop();
-                },
-                _ => {}
+        for c in expr.chars() {
+            if c == char {
+                char_count += 1;
             }
         }
 
-        while stack.len() > 0 {
-            queue.push(stack.pop().unwrap());
-        }
-
-        queue
+        char_count
     }
 
-    pub fn evaluate(mut tokens: Vec<Token>) -> Option<f32> {
-        tokens.reverse();
-
-        let mut stack: Vec<f32> = Vec::new();
-        while let Some(token) = tokens.pop() {
-            match token {
-                Token::Number(num) => stack.push(num as f32),
-                Token::Operator(Operator::Add) => {
-                    let right = stack.pop().unwrap();
-                    let left = stack.pop().unwrap();
-                    stack.push(left + right);
-                },
-                Token::Operator(Operator::Sub) => {
-                    let right = stack.pop().unwrap();
-                    let left = stack.pop().unwrap();
-                    stack.push(left - right);
-                },
-                Token::Operator(Operator::Mul) => {
-                    let right = stack.pop().unwrap();
-                    let left = stack.pop().unwrap();
-                    stack.push(left * right);
-                },
-                Token::Operator(Operator::Div) => {
-                    let right = stack.pop().unwrap();
-                    let left = stack.pop().unwrap();
-                    stack.push(left / right);
-                },
-                _ => {}
-            }
+    /// Builds the display, highlighting the part of the expression an error
+    /// points at.
+    fn display(&self) -> iced::Element<'_, Message> {
+        match &self.error {
+            Some(error) => {
+                let range = error.span().range();
+                let start = range.start.min(self.expression.len());
+                let end = range.end.clamp(start, self.expression.len());
+
+                column![
+                    row![
+                        text(&self.expression[..start]).size(28),
+                        text(&self.expression[start..end]).size(28).style(color!(0xff5555)),
+                        text(&self.expression[end..]).size(28),
+                    ],
+                    text(error.to_string()).size(14),
+                ]
+                .align_items(Alignment::End)
+                .into()
+            },
+            None => text(self.expression.clone()).size(40).into(),
         }
+    }
 
-        if stack.len() > 1 {
-            None
-        } else {
-            stack.pop()
-        }
+    /// Lists the user-defined functions of the current mode, each with a
+    /// button to remove it.
+    fn functions(&self) -> iced::Element<'_, Message> {
+        let functions: Vec<(String, String)> = match self.mode {
+            Mode::Standard => self.environment.functions().map(|function| (function.name.clone(), function.to_string())).collect(),
+            Mode::Programmer => self.programmer.functions().map(|function| (function.name.clone(), function.to_string())).collect(),
+        };
+
+        let definitions = functions.into_iter().map(|(name, definition)| {
+            row![
+                text(definition).size(14),
+                button(text("×").size(14)).padding(0).on_press(Message::RemoveFunction(name)),
+            ]
+            .spacing(ROW_SPACE)
+            .align_items(Alignment::Center)
+            .into()
+        });
+
+        Column::with_children(definitions).align_items(Alignment::End).into()
     }
 
-    pub fn resolve(expression: String) -> Option<String> {
-        let tokens = Calculator::parse(expression.to_owned());
-        let expr = Calculator::expression(tokens.unwrap());
-        let result = Calculator::evaluate(expr)?;
-    
-        let result_string = if result.fract() == 0.0 {
-            format!("{:.0}", result)
-        } else {
-            format!("{:?}", result)
+    /// Builds the row of small buttons switching modes and, in programmer
+    /// mode, picking the word size and signedness.
+    fn mode_bar(&self) -> iced::Element<'_, Message> {
+        let small = |label: String, active: bool, message: Message| {
+            let style = if active { iced::theme::Button::Primary } else { iced::theme::Button::Secondary };
+            button(text(label).size(14)).padding([0, 4]).style(style).on_press(message).into()
+        };
+
+        let buttons: Vec<iced::Element<'_, Message>> = match self.mode {
+            Mode::Standard => vec![small("Programmer".to_owned(), false, Message::ToggleMode)],
+            Mode::Programmer => {
+                let context = &self.programmer.settings.context;
+                let mut buttons: Vec<iced::Element<'_, Message>> = WordSize::ALL
+                    .iter()
+                    .map(|size| small(size.bits().to_string(), *size == context.size, Message::SetWordSize(*size)))
+                    .collect();
+                let signedness = if context.signed { "signed" } else { "unsigned" };
+                buttons.push(small(signedness.to_owned(), false, Message::ToggleSigned));
+                buttons.push(small("Standard".to_owned(), false, Message::ToggleMode));
+                buttons
+            },
         };
-    
-        Some(result_string)
+
+        Row::with_children(buttons).spacing(4).into()
     }
 
-    pub fn count_char(char: char, expr: String) -> u32 {
-        let mut char_count: u32 = 0;
+    /// Builds the programmer readout: the result in hexadecimal, decimal and
+    /// octal, followed by a grid of its bits that can be clicked to flip
+    /// them, the highest bit first.
+    fn word_view(&self) -> iced::Element<'_, Message> {
+        let context = &self.programmer.settings.context;
+        let word = self.word.unwrap_or_else(|| Word::new(0, context));
+
+        let mut readout: Vec<iced::Element<'_, Message>> = Radix::ALL
+            .iter()
+            .filter(|radix| **radix != Radix::Binary)
+            .map(|radix| {
+                row![
+                    text(radix.label()).size(12),
+                    text(word.to_radix(*radix, context)).size(12),
+                ]
+                .spacing(8)
+                .into()
+            })
+            .collect();
+
+        let bits = context.size.bits();
+        let columns = bits.min(screen_settings::BITS_IN_ROW);
+        let width = (screen_settings::get_app_width() - (columns - 1) as f32 * ROW_SPACE) / columns as f32;
+        for first in (0..bits).step_by(columns as usize).rev() {
+            let cells = (first..first + columns).rev().map(|index| {
+                let label = if word.bit(index, context) { "1" } else { "0" };
+                button(text(label).size(12).horizontal_alignment(alignment::Horizontal::Center))
+                    .padding(0)
+                    .width(width)
+                    .style(iced::theme::Button::Secondary)
+                    .on_press(Message::ToggleBit(index))
+                    .into()
+            });
+            readout.push(Row::with_children(cells).spacing(ROW_SPACE).into());
+        }
 
-        for c in expr.chars() {
-            if c == char {
-                char_count += 1;
-            }
+        Column::with_children(readout).spacing(2).align_items(Alignment::End).into()
+    }
+
+    /// Reads the programmer result again after the word size or signedness
+    /// changed, keeping its lowest bits.
+    fn rewrap_word(&mut self) {
+        if let Some(word) = self.word {
+            let word = Word::new(word.value(), &self.programmer.settings.context);
+            self.expression = engine::format_result_with(&word, &self.programmer.settings);
+            self.word = Some(word);
         }
+    }
+
+    /// The keypad of the standard mode.
+    fn standard_keypad(&self) -> iced::Element<'_, Message> {
+        column![
+            // First Row
+            row![
+                calculator_button::misc_action("x^y", false, Message::Edit('^')),
+                calculator_button::misc_action("π", false, Message::Edit('π')),
+                calculator_button::misc_action("Ans", false, Message::Insert("ans")),
+                calculator_button::misc_action("%", false, Message::Edit('%')),
+            ]
+            .spacing(ROW_SPACE),
+            row![
+                calculator_button::misc_action("AC", false, Message::Clear),
+                calculator_button::misc_action("(", false, Message::Edit('(')),
+                calculator_button::misc_action(")", false, Message::Edit(')')),
+                calculator_button::operator_action("/", false),
+            ]
+            .spacing(ROW_SPACE),
+            digit_rows(),
+            row![
+                calculator_button::number_action("0", false),
+                calculator_button::number_action(".", false),
+                calculator_button::misc_action("a/b", false, Message::ToggleFraction),
+                calculator_button::resolve_action("=", false),
+            ]
+            .spacing(ROW_SPACE)
+        ]
+        .spacing(COLUMN_SPACE)
+        .into()
+    }
 
-        return char_count
+    /// The keypad of the programmer mode, with hexadecimal digits and the
+    /// bitwise operators.
+    fn programmer_keypad(&self) -> iced::Element<'_, Message> {
+        column![
+            row![
+                calculator_button::number_action("A", false),
+                calculator_button::number_action("B", false),
+                calculator_button::number_action("C", false),
+                calculator_button::number_action("D", false),
+            ]
+            .spacing(ROW_SPACE),
+            row![
+                calculator_button::number_action("E", false),
+                calculator_button::number_action("F", false),
+                calculator_button::misc_action("<<", false, Message::EditOperator("<<")),
+                calculator_button::misc_action(">>", false, Message::EditOperator(">>")),
+            ]
+            .spacing(ROW_SPACE),
+            row![
+                calculator_button::operator_action("&", false),
+                calculator_button::operator_action("|", false),
+                calculator_button::misc_action("xor", false, Message::EditOperator(" xor ")),
+                calculator_button::misc_action("~", false, Message::Edit('~')),
+            ]
+            .spacing(ROW_SPACE),
+            row![
+                calculator_button::misc_action("AC", false, Message::Clear),
+                calculator_button::misc_action("(", false, Message::Edit('(')),
+                calculator_button::misc_action(")", false, Message::Edit(')')),
+                calculator_button::operator_action("/", false),
+            ]
+            .spacing(ROW_SPACE),
+            digit_rows(),
+            row![
+                calculator_button::number_action("0", false),
+                calculator_button::misc_action("0x", false, Message::Insert("0x")),
+                calculator_button::misc_action("Ans", false, Message::Insert("ans")),
+                calculator_button::resolve_action("=", false),
+            ]
+            .spacing(ROW_SPACE)
+        ]
+        .spacing(COLUMN_SPACE)
+        .into()
     }
 
 }
 
+/// The rows of digits from `1` to `9` with the operators beside them,
+/// shared by every keypad.
+fn digit_rows<'a>() -> iced::Element<'a, Message> {
+    column![
+        row![
+            calculator_button::number_action("7", false),
+            calculator_button::number_action("8", false),
+            calculator_button::number_action("9", false),
+            calculator_button::operator_action("*", false),
+        ]
+        .spacing(ROW_SPACE),
+        row![
+            calculator_button::number_action("4", false),
+            calculator_button::number_action("5", false),
+            calculator_button::number_action("6", false),
+            calculator_button::operator_action("-", false),
+        ]
+        .spacing(ROW_SPACE),
+        row![
+            calculator_button::number_action("1", false),
+            calculator_button::number_action("2", false),
+            calculator_button::number_action("3", false),
+            calculator_button::operator_action("+", false),
+        ]
+        .spacing(ROW_SPACE),
+    ]
+    .spacing(COLUMN_SPACE)
+    .into()
+}
+
+/// Runs a line in `environment`.
+///
+/// # Returns
+/// Returns the text to display and the value, if the line produced one
+/// rather than a function definition.
+fn run<N: Number>(environment: &mut Environment<N>, expression: &str) -> Result<(String, Option<N>), Error> {
+    Ok(match environment.execute(expression)? {
+        Outcome::Value(value) => (engine::format_result_with(&value, &environment.settings), Some(value)),
+        Outcome::Boolean(boolean) => (boolean.to_string(), None),
+        Outcome::Quantity(quantity) => (engine::format_value_with(&Value::Quantity(quantity), &environment.settings), None),
+        Outcome::Definition(name) => (environment.function(&name).map(ToString::to_string).unwrap_or(name), None),
+    })
+}
+
+/// Returns `true` when the expression ends with a `0x` literal, after which
+/// the digits `A` to `F` can be typed.
+fn ends_hexadecimal_literal(expression: &str) -> bool {
+    let start = expression.rfind(|c: char| !c.is_ascii_alphanumeric()).map_or(0, |index| index + 1);
+
+    expression[start..].starts_with("0x")
+}
+
 #[derive(Debug, Clone)]
 pub enum Message {
     Edit(char),
+    /// Appends an operator written with several characters, such as `<<`.
+    EditOperator(&'static str),
+    /// Appends a name standing for a value, such as `ans`, or the `0x` of a
+    /// hexadecimal literal.
+    Insert(&'static str),
     Clear,
     Resolve,
+    /// Forgets the user-defined function with the given name.
+    RemoveFunction(String),
+    ToggleFraction,
+    /// Switches between the standard and the programmer keypads.
+    ToggleMode,
+    SetWordSize(WordSize),
+    /// Switches the programmer mode between signed and unsigned words.
+    ToggleSigned,
+    /// Flips a bit of the programmer result, the lowest one being `0`.
+    ToggleBit(u32),
 }
 
 impl Application for Calculator {
@@ -251,7 +355,20 @@ impl Application for Calculator {
 
     fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
         (
-            Calculator { expression: "0".to_owned(), result: None },
+            Calculator {
+                expression: "0".to_owned(),
+                result: None,
+                error: None,
+                environment: Environment::new(Settings {
+                    // Decimal fractions are exact but can be arbitrarily long,
+                    // so large results are shown in scientific notation.
+                    notation: Notation::Scientific,
+                    ..Settings::new(RationalContext { style: FractionStyle::Decimal, ..Default::default() })
+                }),
+                mode: Mode::Standard,
+                word: None,
+                programmer: Environment::default(),
+            },
             Command::none()
         )
     }
@@ -261,6 +378,10 @@ impl Application for Calculator {
     fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
         match message {
             Message::Edit(element) => {
+                self.error = None;
+                self.result = None;
+                self.word = None;
+
                 let mut owned_expression = self.expression.to_owned();
 
                 if owned_expression.eq("0") && element != '.' {
@@ -273,15 +394,31 @@ impl Application for Calculator {
                     return Command::none()
                 }
 
-                if (element == '+' || element == '-' ||  element == '/' || element == '*') && (!last_char_in_expression.is_numeric() && last_char_in_expression != ')') {
+                let ends_value = last_char_in_expression.is_alphanumeric() || last_char_in_expression == ')' || last_char_in_expression == '%';
+
+                let is_negation = element == '-' && (last_char_in_expression == '?' || "(*/^&|~<>".contains(last_char_in_expression));
+
+                if (element == '+' || element == '-' ||  element == '/' || element == '*' || element == '^' || element == '&' || element == '|') && !ends_value && !is_negation {
                     return Command::none()
                 }
 
-                if element == ')' && !last_char_in_expression.is_numeric() {
+                if element == '~' && ends_value {
                     return Command::none()
                 }
 
-                if element == '(' && (last_char_in_expression.is_numeric() || last_char_in_expression == '.' || last_char_in_expression == ')') {
+                // Hexadecimal digits only make sense inside a `0x` literal.
+                if ('A'..='F').contains(&element) && !ends_hexadecimal_literal(&owned_expression) {
+                    return Command::none()
+                }
+
+                if (element == ')' || element == '%') && !ends_value {
+                    return Command::none()
+                }
+
+                // A bracket or a name right after a number or a `)` multiplies it,
+                // but right after a name it would read as a function call or a
+                // longer name.
+                if (element == '(' || element == 'π') && (last_char_in_expression.is_alphabetic() || last_char_in_expression == '.') {
                     return Command::none()
                 }
 
@@ -289,12 +426,116 @@ impl Application for Calculator {
 
                 self.expression = owned_expression;
             },
+            Message::Insert(name) => {
+                self.error = None;
+                self.result = None;
+
+                if self.expression.eq("0") {
+                    self.expression.clear();
+                }
+
+                let last_char_in_expression = self.expression.chars().last().unwrap_or('?');
+
+                if last_char_in_expression.is_alphabetic() || last_char_in_expression == '.' {
+                    return Command::none()
+                }
+
+                // A `0x` right after a digit would read as part of that number.
+                if last_char_in_expression.is_ascii_digit() && name.starts_with(|c: char| c.is_ascii_digit()) {
+                    return Command::none()
+                }
+
+                self.expression.push_str(name);
+            },
+            Message::EditOperator(operator) => {
+                self.error = None;
+                self.result = None;
+                self.word = None;
+
+                let last_char_in_expression = self.expression.chars().last().unwrap_or('?');
+
+                if !(last_char_in_expression.is_alphanumeric() || last_char_in_expression == ')') {
+                    return Command::none()
+                }
+
+                self.expression.push_str(operator);
+            },
             Message::Clear => {
-                self.expression = String::new()
+                self.expression = String::new();
+                self.error = None;
+                self.result = None;
+                self.word = None;
             },
             Message::Resolve => {
-                self.result = Calculator::resolve(self.expression.to_owned());
-                self.expression = self.result.clone().unwrap_or("".to_owned());
+                let outcome = match self.mode {
+                    Mode::Standard => run(&mut self.environment, &self.expression).map(|(text, result)| {
+                        self.result = result;
+                        text
+                    }),
+                    Mode::Programmer => run(&mut self.programmer, &self.expression).map(|(text, word)| {
+                        self.word = word;
+                        text
+                    }),
+                };
+
+                match outcome {
+                    Ok(text) => self.expression = text,
+                    Err(error) => self.error = Some(error),
+                }
+            },
+            Message::RemoveFunction(name) => {
+                match self.mode {
+                    Mode::Standard => {
+                        self.environment.remove_function(&name);
+                    },
+                    Mode::Programmer => {
+                        self.programmer.remove_function(&name);
+                    },
+                }
+            },
+            Message::ToggleFraction => {
+                let settings = &mut self.environment.settings;
+                settings.context.style = match settings.context.style {
+                    FractionStyle::Decimal => FractionStyle::Fraction,
+                    _ => FractionStyle::Decimal,
+                };
+
+                if let Some(result) = &self.result {
+                    self.expression = engine::format_result_with(result, settings);
+                }
+            },
+            Message::ToggleMode => {
+                self.mode = match self.mode {
+                    Mode::Standard => Mode::Programmer,
+                    Mode::Programmer => Mode::Standard,
+                };
+                self.expression = "0".to_owned();
+                self.error = None;
+                self.result = None;
+                self.word = None;
+
+                let height = match self.mode {
+                    Mode::Standard => screen_settings::get_app_height(),
+                    Mode::Programmer => screen_settings::get_programmer_app_height(),
+                };
+                return window::resize(window::Id::MAIN, Size { width: screen_settings::get_app_width(), height });
+            },
+            Message::SetWordSize(size) => {
+                self.programmer.settings.context.size = size;
+                self.rewrap_word();
+            },
+            Message::ToggleSigned => {
+                let context = &mut self.programmer.settings.context;
+                context.signed = !context.signed;
+                self.rewrap_word();
+            },
+            Message::ToggleBit(index) => {
+                let context = &self.programmer.settings.context;
+                let word = self.word.unwrap_or_else(|| Word::new(0, context)).toggle_bit(index, context);
+
+                self.expression = engine::format_result_with(&word, &self.programmer.settings);
+                self.error = None;
+                self.word = Some(word);
             },
         }
         Command::none()
@@ -305,49 +546,28 @@ impl Application for Calculator {
             column![
                 vertical_space(),
                 container(
-                    text(self.expression.clone())
-                        .size(40),
+                    self.display()
                 )
                 .padding(4)
                 .width(screen_settings::get_app_width() - 8.0)
                 .height(60)
                 .center_y()
                 .align_x(alignment::Horizontal::Right),
-                // First Row
-                row![
-                    calculator_button::misc_action("C", false, Message::Clear),
-                    calculator_button::misc_action("(", false, Message::Edit('(')),
-                    calculator_button::misc_action(")", false, Message::Edit(')')),
-                    calculator_button::operator_action("/", false),
-                ]
-                .spacing(ROW_SPACE),
-                row![
-                    calculator_button::number_action("7", false),
-                    calculator_button::number_action("8", false),
-                    calculator_button::number_action("9", false),
-                    calculator_button::operator_action("*", false),
-                ]
-                .spacing(ROW_SPACE),
-                row![
-                    calculator_button::number_action("4", false),
-                    calculator_button::number_action("5", false),
-                    calculator_button::number_action("6", false),
-                    calculator_button::operator_action("-", false),
-                ]
-                .spacing(ROW_SPACE),
-                row![
-                    calculator_button::number_action("1", false),
-                    calculator_button::number_action("2", false),
-                    calculator_button::number_action("3", false),
-                    calculator_button::operator_action("+", false),
-                ]
-                .spacing(ROW_SPACE),
-                row![
-                    calculator_button::number_action("0", true),
-                    calculator_button::number_action(".", false),
-                    calculator_button::resolve_action("=", false),
-                ]
-                .spacing(ROW_SPACE)
+                container(
+                    self.functions()
+                )
+                .width(screen_settings::get_app_width() - 8.0)
+                .align_x(alignment::Horizontal::Right),
+                container(
+                    self.mode_bar()
+                )
+                .width(screen_settings::get_app_width())
+                .height(screen_settings::MODE_BAR_HEIGHT)
+                .align_x(alignment::Horizontal::Right),
+                match self.mode {
+                    Mode::Standard => self.standard_keypad(),
+                    Mode::Programmer => column![self.word_view(), self.programmer_keypad()].spacing(COLUMN_SPACE).into(),
+                },
             ]
             .spacing(COLUMN_SPACE)
         )
@@ -21,7 +21,7 @@ impl button::StyleSheet for ButtonStyle {
 
     fn active(&self, _style: &Self::Style) -> button::Appearance {
         button::Appearance {
-            text_color: color!(0xffffff).into(),
+            text_color: color!(0xffffff),
             background: get_background_color(self),
             border: Border::with_radius(0),
             ..Default::default()
@@ -30,7 +30,7 @@ impl button::StyleSheet for ButtonStyle {
 
     fn hovered(&self, _style: &Self::Style) -> button::Appearance {
         button::Appearance {
-            text_color: color!(0xffffff).into(),
+            text_color: color!(0xffffff),
             background: get_hovered_background_color(self),
             border: Border::with_radius(0),
             ..Default::default()
@@ -39,7 +39,7 @@ impl button::StyleSheet for ButtonStyle {
 
     fn pressed(&self, _style: &Self::Style) -> button::Appearance {
         button::Appearance {
-            text_color: color!(0x666666).into(),
+            text_color: color!(0x666666),
             background: get_pressed_background_color(self),
             border: Border::with_radius(0),
             ..Default::default()
@@ -75,7 +75,7 @@ fn get_pressed_background_color(_style: &ButtonStyle) ->Option< Background> {
     }
 }
 
-pub fn number_action<'a>(label: &'a str, double_size: bool) -> Element<calculator::Message, Theme> {
+pub fn number_action<'a>(label: &'a str, double_size: bool) -> Element<'a, calculator::Message, Theme> {
     let size_multiplier: f32 = if double_size { 2.0 } else { 1.0 };
     let aditional_space: f32 = if double_size { ROW_SPACE } else { 0.0 };
 
@@ -96,7 +96,7 @@ pub fn number_action<'a>(label: &'a str, double_size: bool) -> Element<calculato
         .into() 
 }
 
-pub fn operator_action<'a>(label: &'a str, double_size: bool) -> Element<calculator::Message, Theme> {
+pub fn operator_action<'a>(label: &'a str, double_size: bool) -> Element<'a, calculator::Message, Theme> {
     let size_multiplier: f32 = if double_size { 2.0 } else { 1.0 };
     let aditional_space: f32 = if double_size { ROW_SPACE } else { 0.0 };
 
@@ -117,7 +117,7 @@ pub fn operator_action<'a>(label: &'a str, double_size: bool) -> Element<calcula
         .into() 
 }
 
-pub fn resolve_action<'a>(label: &'a str, double_size: bool) -> Element<calculator::Message, Theme> {
+pub fn resolve_action<'a>(label: &'a str, double_size: bool) -> Element<'a, calculator::Message, Theme> {
     let size_multiplier: f32 = if double_size { 2.0 } else { 1.0 };
     let aditional_space: f32 = if double_size { ROW_SPACE } else { 0.0 };
 
@@ -138,7 +138,7 @@ pub fn resolve_action<'a>(label: &'a str, double_size: bool) -> Element<calculat
         .into() 
 }
 
-pub fn misc_action<'a>(label: &'a str, double_size: bool, message: calculator::Message) -> Element<calculator::Message, Theme> {
+pub fn misc_action<'a>(label: &'a str, double_size: bool, message: calculator::Message) -> Element<'a, calculator::Message, Theme> {
     let size_multiplier: f32 = if double_size { 2.0 } else { 1.0 };
     let aditional_space: f32 = if double_size { ROW_SPACE } else { 0.0 };
 
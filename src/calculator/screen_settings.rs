@@ -3,8 +3,16 @@ pub static BUTTON_HEIGHT: f32 = 47.5;
 pub static COLUMN_SPACE: f32 = 0.5;
 pub static ROW_SPACE: f32 = 0.5;
 pub static ELEMENTS_IN_ROW: u16 = 4;
-pub static ELEMENTS_IN_COLUMN: u16 = 5;
+pub static ELEMENTS_IN_COLUMN: u16 = 6;
 pub static DISPLAY_HEIGHT: f32 = 60.0;
+pub static MODE_BAR_HEIGHT: f32 = 22.0;
+pub static PROGRAMMER_ELEMENTS_IN_COLUMN: u16 = 8;
+/// The height of the hexadecimal, decimal and octal readout of the
+/// programmer mode.
+pub static READOUT_HEIGHT: f32 = 48.0;
+/// The number of bits in each row of the programmer bit grid.
+pub static BITS_IN_ROW: u32 = 16;
+pub static BIT_ROW_HEIGHT: f32 = 18.0;
 
 /// Calculates the total width of the application window based on the number
 /// of elements per row, the space between elements, and the width
@@ -26,7 +34,7 @@ pub fn get_app_width() -> f32 {
     let total_space = (ELEMENTS_IN_ROW - 2) as f32 * ROW_SPACE;
     let total_items = ELEMENTS_IN_ROW as f32 * BUTTON_WIDTH;
 
-    total_space + (total_items) as f32
+    total_space + total_items
 }
 
 /// Calculates the total height of the application window based on the number
@@ -49,5 +57,19 @@ pub fn get_app_height() -> f32 {
     let total_space = (ELEMENTS_IN_COLUMN - 1) as f32 * COLUMN_SPACE;
     let total_items = ELEMENTS_IN_COLUMN as f32 * BUTTON_HEIGHT;
 
-    total_space + (total_items) + DISPLAY_HEIGHT as f32
+    total_space + total_items + DISPLAY_HEIGHT + MODE_BAR_HEIGHT
+}
+
+/// Calculates the total height of the application window in programmer
+/// mode, which adds a readout of the result, a grid of its bits for the
+/// largest word size and rows of buttons to the standard layout.
+///
+/// # Returns
+/// Returns the total height of the application window as a `f32` value.
+pub fn get_programmer_app_height() -> f32 {
+    let total_space = PROGRAMMER_ELEMENTS_IN_COLUMN as f32 * COLUMN_SPACE;
+    let total_items = PROGRAMMER_ELEMENTS_IN_COLUMN as f32 * BUTTON_HEIGHT;
+    let bit_rows = 64 / BITS_IN_ROW;
+
+    total_space + total_items + DISPLAY_HEIGHT + MODE_BAR_HEIGHT + READOUT_HEIGHT + bit_rows as f32 * BIT_ROW_HEIGHT
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+//! The expression engine behind the calculator.
+//!
+//! Evaluating an expression goes through four steps, each of them exposed
+//! on its own so other tools can reuse only the parts they need:
+//!
+//! 1. `tokenize` turns the text into a list of `Token`s, reading names
+//!    followed by `(` as calls to the `functions` library and other names
+//!    as `constants`.
+//! 2. `to_rpn` reorders the tokens into reverse polish notation using the
+//!    shunting-yard algorithm.
+//! 3. `evaluate` reduces the reverse polish tokens to a single value.
+//! 4. `format_result` turns the value into the text shown to the user.
+//!
+//! `resolve` chains all of them together. Every step is generic over the
+//! `Number` backend used for the values, with `f64` as the default; use
+//! `resolve_as` to pick another one, such as the exact `decimal::Decimal`,
+//! `rational::Rational` or `integer::Integer`, `complex::Complex` for
+//! numbers with an imaginary part, or `word::Word` for the wrapping
+//! fixed-size integers of programming work, and the `_with` variants to
+//! pass `Settings` for the backend and the engine. An `Environment` keeps
+//! variables assigned with `name = expression` and functions defined with
+//! `name(x) = expression` from one evaluation to the next. Names of `units`
+//! such as `m`, `km/h` or `ft` turn numbers into quantities, which are
+//! checked for matching dimensions and converted with `to` or `in`. None of
+//! this module depends on the GUI, so it is available with
+//! `default-features = false`.
+
+pub mod bitwise;
+pub mod complex;
+pub mod constants;
+pub mod decimal;
+pub mod environment;
+pub mod error;
+pub mod evaluator;
+pub mod formatter;
+pub mod functions;
+pub mod integer;
+pub mod number;
+pub mod rational;
+pub mod settings;
+pub mod shunting_yard;
+pub mod span;
+pub mod token;
+pub mod tokenizer;
+pub mod units;
+pub mod value;
+pub mod word;
+
+pub use constants::Constant;
+pub use environment::Environment;
+pub use error::Error;
+pub use evaluator::{evaluate, evaluate_in, evaluate_value_in, evaluate_with};
+pub use formatter::{format_result, format_result_with, format_value_with};
+pub use functions::Function;
+pub use number::{ArithmeticError, Number, Remainder};
+pub use settings::{ImplicitMultiplication, Notation, Settings};
+pub use shunting_yard::to_rpn;
+pub use span::{Span, Spanned};
+pub use token::{Associativity, Operator, Precedence, Token};
+pub use tokenizer::{tokenize, tokenize_with};
+pub use units::{Quantity, Unit};
+pub use value::Value;
+
+/// Tokenizes, converts and evaluates an expression in one go using `f64`
+/// values.
+///
+/// # Returns
+/// Returns the formatted result, or the `Error` that stopped the
+/// expression from being evaluated.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve, Error, Operator, Span};
+///
+/// assert_eq!(resolve("2 * (3 + 4)"), Ok("14".to_owned()));
+/// assert_eq!(resolve("2 +"), Err(Error::TrailingOperator(Operator::Add, Span::new(2, 3))));
+/// ```
+pub fn resolve<T: AsRef<str>>(expr: T) -> Result<String, Error> {
+    resolve_as::<f64>(expr.as_ref())
+}
+
+/// Tokenizes, converts and evaluates an expression in one go using the
+/// backend `N`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::resolve_as;
+///
+/// assert_eq!(resolve_as::<f32>("0.5 * 3"), Ok("1.5".to_owned()));
+/// ```
+pub fn resolve_as<N: Number>(expr: &str) -> Result<String, Error> {
+    resolve_with::<N>(expr, &Settings::default())
+}
+
+/// Tokenizes, converts and evaluates an expression in one go using the
+/// backend `N` with the given `settings`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve_with, Settings};
+/// use rustcalculator::engine::decimal::{Decimal, DecimalContext, Rounding};
+///
+/// let settings = Settings::new(DecimalContext { precision: 2, rounding: Rounding::HalfUp });
+///
+/// assert_eq!(resolve_with::<Decimal>("2 / 3", &settings), Ok("0.67".to_owned()));
+/// ```
+pub fn resolve_with<N: Number>(expr: &str, settings: &Settings<N>) -> Result<String, Error> {
+    let result = Environment::new(settings.clone()).evaluate(expr)?;
+
+    Ok(format_value_with(&result, settings))
+}
+
+/// Tokenizes, converts and evaluates an expression using the backend `N`
+/// with the given `settings`, without formatting the result. Useful to keep
+/// the value around and display it in several ways.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{compute_with, format_result_with, Settings};
+/// use rustcalculator::engine::rational::{FractionStyle, Rational};
+///
+/// let mut settings = Settings::default();
+/// let value = compute_with::<Rational>("1 / 4", &settings).unwrap();
+/// assert_eq!(format_result_with(&value, &settings), "1/4");
+///
+/// settings.context.style = FractionStyle::Decimal;
+/// assert_eq!(format_result_with(&value, &settings), "0.25");
+/// ```
+pub fn compute_with<N: Number>(expr: &str, settings: &Settings<N>) -> Result<N, Error> {
+    Environment::new(settings.clone()).compute(expr)
+}
@@ -0,0 +1,46 @@
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive};
+
+use crate::engine::number::{ArithmeticError, Number, MAX_EXACT_POW_BITS};
+use crate::engine::token::Operator;
+
+/// Applies one of the binary bitwise operators `&`, `|`, `xor`, `<<` and
+/// `>>` to two integers. Negative values behave as in two's complement with
+/// infinitely many sign bits, so `-1 & 6` is `6` and `-8 >> 1` is `-4`.
+///
+/// # Returns
+/// Returns `ArithmeticError::NotAnInteger` when an operand has a fractional
+/// part, `Undefined` for a negative shift and `Overflow` when a left shift
+/// would produce a result too large to compute.
+pub fn apply<N: Number>(op: Operator, left: &N, right: &N, context: &N::Context) -> Result<N, ArithmeticError> {
+    let left = integer(left)?;
+    let right = integer(right)?;
+
+    let result = match op {
+        Operator::BitAnd => left & right,
+        Operator::BitOr => left | right,
+        Operator::BitXor => left ^ right,
+        Operator::Shl => left << shift(&right)?,
+        Operator::Shr => left >> shift(&right)?,
+        _ => unreachable!("{} is not a binary bitwise operator", op),
+    };
+
+    N::from_integer(&result, context).ok_or(ArithmeticError::Overflow)
+}
+
+/// Flips every bit of an integer, which in two's complement is `-x - 1`.
+pub fn not<N: Number>(operand: &N, context: &N::Context) -> Result<N, ArithmeticError> {
+    N::from_integer(&!integer(operand)?, context).ok_or(ArithmeticError::Overflow)
+}
+
+fn integer<N: Number>(value: &N) -> Result<BigInt, ArithmeticError> {
+    value.to_integer().ok_or(ArithmeticError::NotAnInteger)
+}
+
+fn shift(amount: &BigInt) -> Result<usize, ArithmeticError> {
+    if amount.is_negative() {
+        return Err(ArithmeticError::Undefined);
+    }
+
+    amount.to_usize().filter(|&bits| bits as u64 <= MAX_EXACT_POW_BITS).ok_or(ArithmeticError::Overflow)
+}
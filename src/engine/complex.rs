@@ -0,0 +1,334 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::engine::functions::{self, Function};
+use crate::engine::number::{ArithmeticError, Number, Remainder};
+
+/// How a `Complex` result is written out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComplexForm {
+    /// A real and an imaginary part, such as `1 + 2i`.
+    #[default]
+    Rectangular,
+    /// A magnitude and an angle in radians, such as `2e^(1.5i)`, which reads
+    /// back as the same number.
+    Polar,
+}
+
+/// The settings a `Complex` result is displayed with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComplexContext {
+    pub form: ComplexForm,
+}
+
+/// A complex number backend, computing with a pair of `f64`s.
+///
+/// The name `i` stands for the imaginary unit, so `3 + 2i` is read as a
+/// complex number, and functions such as `sqrt` or `ln` of negative numbers
+/// have a result. Complex numbers have no order, so `<` and the like only
+/// work on real ones, as do `%`, `//` and the rounding functions.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve_as, resolve_with, Settings};
+/// use rustcalculator::engine::complex::{Complex, ComplexContext, ComplexForm};
+///
+/// assert_eq!(resolve_as::<Complex>("sqrt(-4)"), Ok("2i".to_owned()));
+/// assert_eq!(resolve_as::<Complex>("(1 + 2i) * (3 - i)"), Ok("5 + 5i".to_owned()));
+///
+/// let context = ComplexContext { form: ComplexForm::Polar };
+/// assert_eq!(resolve_with::<Complex>("-2", &Settings::new(context)), Ok("2e^(3.141592653589793i)".to_owned()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// Creates a complex number. Negative zeros are stored as zeros, so
+    /// that `-8` lies on the positive side of the branch cut of `ln` and
+    /// `(-8) ^ (1 / 3)` is the principal root.
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex { re: re + 0.0, im: im + 0.0 }
+    }
+
+    /// Returns the distance from zero.
+    pub fn magnitude(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the angle from the positive real axis, between `-π` and `π`.
+    pub fn angle(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns `true` when the imaginary part is zero.
+    pub fn is_real(&self) -> bool {
+        self.im == 0.0
+    }
+
+    fn exp(&self) -> Self {
+        let scale = self.re.exp();
+        Complex::new(scale * self.im.cos(), scale * self.im.sin())
+    }
+
+    fn ln(&self) -> Result<Self, ArithmeticError> {
+        if self.re == 0.0 && self.im == 0.0 {
+            return Err(ArithmeticError::Undefined);
+        }
+        Ok(Complex::new(self.magnitude().ln(), self.angle()))
+    }
+
+    /// Returns the square root with a non-negative real part, computed so
+    /// that `sqrt(-4)` is exactly `2i`.
+    fn sqrt(&self) -> Self {
+        let magnitude = self.magnitude();
+        let re = ((magnitude + self.re) / 2.0).sqrt();
+        let im = ((magnitude - self.re) / 2.0).sqrt();
+
+        Complex::new(re, if self.im < 0.0 { -im } else { im })
+    }
+
+    fn sin(&self) -> Self {
+        Complex::new(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
+    }
+
+    fn cos(&self) -> Self {
+        Complex::new(self.re.cos() * self.im.cosh(), -(self.re.sin() * self.im.sinh()))
+    }
+
+    /// Raises to a whole power by repeated squaring, which keeps powers of
+    /// `i` exact.
+    fn powi(&self, exponent: i64, context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        let (mut base, mut remaining, mut result) = (*self, exponent.unsigned_abs(), Complex::new(1.0, 0.0));
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result.mul(&base, context)?;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                base = base.mul(&base, context)?;
+            }
+        }
+
+        if exponent < 0 {
+            Complex::new(1.0, 0.0).div(&result, context)
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Writes the number as `a + bi`.
+    pub fn to_rectangular(&self) -> String {
+        let (re, im) = self.cleaned();
+        if im == 0.0 {
+            return component(re);
+        }
+
+        let imaginary = if im.abs() == 1.0 { "i".to_owned() } else { format!("{}i", component(im.abs())) };
+        match (re == 0.0, im < 0.0) {
+            (true, false) => imaginary,
+            (true, true) => format!("-{}", imaginary),
+            (false, false) => format!("{} + {}", component(re), imaginary),
+            (false, true) => format!("{} - {}", component(re), imaginary),
+        }
+    }
+
+    /// Writes the number as its magnitude times `e^(θi)`, as in
+    /// `2e^(1.5i)`, leaving out the angle of positive real numbers.
+    pub fn to_polar(&self) -> String {
+        let magnitude = self.magnitude();
+        let angle = self.angle();
+        if magnitude == 0.0 || angle == 0.0 {
+            return component(magnitude);
+        }
+
+        let scale = if magnitude == 1.0 { String::new() } else { component(magnitude) };
+        format!("{}e^({}i)", scale, component(angle))
+    }
+
+    /// Returns the parts with rounding noise, such as the `1.2e-16i` left by
+    /// `e^(πi)`, dropped when it is negligible next to the other part.
+    fn cleaned(&self) -> (f64, f64) {
+        const NOISE: f64 = 1e-12;
+
+        if self.im.abs() < self.re.abs() * NOISE {
+            (self.re, 0.0)
+        } else if self.re.abs() < self.im.abs() * NOISE {
+            (0.0, self.im)
+        } else {
+            (self.re, self.im)
+        }
+    }
+
+    /// Returns the real part, failing for numbers that are not real.
+    fn real(&self) -> Result<f64, ArithmeticError> {
+        if self.is_real() {
+            Ok(self.re)
+        } else {
+            Err(ArithmeticError::Undefined)
+        }
+    }
+}
+
+impl Number for Complex {
+    type Context = ComplexContext;
+
+    fn from_literal(literal: &str, context: &ComplexContext) -> Option<Self> {
+        Self::from_f64(f64::from_literal(literal, &())?, context)
+    }
+
+    fn from_scientific(mantissa: &str, exponent: i32, context: &ComplexContext) -> Option<Self> {
+        Self::from_f64(f64::from_scientific(mantissa, exponent, &())?, context)
+    }
+
+    fn add(&self, rhs: &Self, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        finite(Complex::new(self.re + rhs.re, self.im + rhs.im))
+    }
+
+    fn sub(&self, rhs: &Self, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        finite(Complex::new(self.re - rhs.re, self.im - rhs.im))
+    }
+
+    fn mul(&self, rhs: &Self, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        if self.is_real() && rhs.is_real() {
+            return finite(Complex::new(self.re * rhs.re, 0.0));
+        }
+        finite(Complex::new(self.re * rhs.re - self.im * rhs.im, self.re * rhs.im + self.im * rhs.re))
+    }
+
+    fn div(&self, rhs: &Self, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        if rhs.re == 0.0 && rhs.im == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        if rhs.is_real() {
+            return finite(Complex::new(self.re / rhs.re, self.im / rhs.re));
+        }
+
+        let scale = rhs.re * rhs.re + rhs.im * rhs.im;
+        let re = (self.re * rhs.re + self.im * rhs.im) / scale;
+        let im = (self.im * rhs.re - self.re * rhs.im) / scale;
+        finite(Complex::new(re, im))
+    }
+
+    fn neg(&self, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        Ok(Complex::new(-self.re, -self.im))
+    }
+
+    /// Real powers of non-negative real numbers and whole powers of real
+    /// numbers are computed like `f64`, other whole powers by repeated
+    /// multiplication and everything else through `e^(rhs * ln(self))`.
+    fn pow(&self, rhs: &Self, context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        if self.re == 0.0 && self.im == 0.0 {
+            if rhs.re == 0.0 && rhs.im == 0.0 {
+                return Ok(Complex::new(1.0, 0.0));
+            } else if rhs.re > 0.0 {
+                return Ok(*self);
+            }
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let whole = rhs.is_real() && rhs.re.fract() == 0.0;
+        if self.is_real() && rhs.is_real() && (self.re > 0.0 || whole) {
+            return self.re.pow(&rhs.re, &()).map(|re| Complex::new(re, 0.0));
+        }
+        if whole && rhs.re.abs() <= i64::MAX as f64 {
+            return self.powi(rhs.re as i64, context);
+        }
+
+        let exponent = rhs.mul(&self.ln()?, context)?;
+        finite(exponent.exp())
+    }
+
+    fn rem(&self, rhs: &Self, mode: Remainder, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        self.real()?.rem(&rhs.real()?, mode, &()).map(|re| Complex::new(re, 0.0))
+    }
+
+    fn floor_div(&self, rhs: &Self, _context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        self.real()?.floor_div(&rhs.real()?, &()).map(|re| Complex::new(re, 0.0))
+    }
+
+    /// Only real numbers are ordered.
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        if self.is_real() && other.is_real() {
+            self.re.partial_cmp(&other.re)
+        } else {
+            None
+        }
+    }
+
+    /// Only real numbers have an `f64` value.
+    fn to_f64(&self) -> Option<f64> {
+        self.real().ok()
+    }
+
+    fn from_f64(value: f64, _context: &ComplexContext) -> Option<Self> {
+        Some(Complex::new(value, 0.0)).filter(|value| value.re.is_finite())
+    }
+
+    fn imaginary_unit(_context: &ComplexContext) -> Option<Self> {
+        Some(Complex::new(0.0, 1.0))
+    }
+
+    /// Functions with a complex counterpart accept any number; the others
+    /// only work on real numbers. Real arguments within the real domain of
+    /// a function are computed like `f64`, so `log10(1000)` stays `3`.
+    fn call(function: Function, args: &[Self], context: &ComplexContext) -> Result<Self, ArithmeticError> {
+        let z = args[0];
+        let real = args.iter().all(Complex::is_real);
+        let positive = real && args.iter().all(|arg| arg.re > 0.0);
+        let ten = Complex::new(10.0, 0.0);
+
+        let result = match function {
+            Function::Sqrt => z.sqrt(),
+            Function::Abs => Complex::new(z.magnitude(), 0.0),
+            Function::Arg => Complex::new(z.angle(), 0.0),
+            Function::Conj => Complex::new(z.re, -z.im),
+            Function::Re => Complex::new(z.re, 0.0),
+            Function::Im => Complex::new(z.im, 0.0),
+            Function::Exp if !real => z.exp(),
+            Function::Ln if !positive => z.ln()?,
+            Function::Log10 if !positive => z.ln()?.div(&ten.ln()?, context)?,
+            Function::Log if !positive => z.ln()?.div(&args.get(1).unwrap_or(&ten).ln()?, context)?,
+            Function::Sin if !real => z.sin(),
+            Function::Cos if !real => z.cos(),
+            Function::Tan if !real => z.sin().div(&z.cos(), context)?,
+            _ => {
+                let values = args.iter().map(Complex::real).collect::<Result<Vec<f64>, ArithmeticError>>()?;
+                let value = functions::approximate::<f64>(function, &values, &())?;
+                Complex::new(value, 0.0)
+            },
+        };
+
+        finite(result)
+    }
+
+    fn format(&self, context: &ComplexContext) -> String {
+        match context.form {
+            ComplexForm::Rectangular => self.to_rectangular(),
+            ComplexForm::Polar => self.to_polar(),
+        }
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_rectangular())
+    }
+}
+
+/// Formats one part of a complex number like an `f64` result.
+fn component(value: f64) -> String {
+    // Avoid printing `-0`.
+    let value = if value == 0.0 { 0.0 } else { value };
+    value.format(&())
+}
+
+fn finite(value: Complex) -> Result<Complex, ArithmeticError> {
+    if value.re.is_finite() && value.im.is_finite() {
+        Ok(value)
+    } else {
+        Err(ArithmeticError::Overflow)
+    }
+}
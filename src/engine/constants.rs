@@ -0,0 +1,46 @@
+/// A named constant known to the engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Constant {
+    /// The ratio of a circle's circumference to its diameter, written `pi`
+    /// or `π`.
+    Pi,
+    /// Euler's number, the base of the natural logarithm.
+    E,
+    /// The ratio of a circle's circumference to its radius, `2π`.
+    Tau,
+    /// The golden ratio, `(1 + sqrt(5)) / 2`.
+    Phi,
+}
+
+impl Constant {
+    /// Looks up a constant by the name used in expressions.
+    ///
+    /// # Example
+    /// ```
+    /// use rustcalculator::engine::constants::Constant;
+    ///
+    /// assert_eq!(Constant::from_name("π"), Some(Constant::Pi));
+    /// assert_eq!(Constant::from_name("pie"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Constant> {
+        let constant = match name {
+            "pi" | "π" => Self::Pi,
+            "e" => Self::E,
+            "tau" | "τ" => Self::Tau,
+            "phi" | "φ" => Self::Phi,
+            _ => return None,
+        };
+
+        Some(constant)
+    }
+
+    /// Returns the value of the constant to the precision of an `f64`.
+    pub fn value(&self) -> f64 {
+        match self {
+            Self::Pi => std::f64::consts::PI,
+            Self::E => std::f64::consts::E,
+            Self::Tau => std::f64::consts::TAU,
+            Self::Phi => 1.618_033_988_749_895,
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive};
+use rust_decimal::RoundingStrategy;
+
+use crate::engine::functions::{self, Function};
+use crate::engine::number::{ArithmeticError, Number, Remainder};
+
+/// How a `Decimal` result with more digits than the configured precision is
+/// rounded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Ties go to the even neighbour, also known as banker's rounding.
+    #[default]
+    HalfEven,
+    /// Ties go away from zero, as taught in school.
+    HalfUp,
+    /// Ties go toward zero.
+    HalfDown,
+    /// Always away from zero.
+    Up,
+    /// Always toward zero, truncating the extra digits.
+    Down,
+    /// Always toward positive infinity.
+    Ceiling,
+    /// Always toward negative infinity.
+    Floor,
+}
+
+impl Rounding {
+    fn strategy(&self) -> RoundingStrategy {
+        match self {
+            Self::HalfEven => RoundingStrategy::MidpointNearestEven,
+            Self::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Self::HalfDown => RoundingStrategy::MidpointTowardZero,
+            Self::Up => RoundingStrategy::AwayFromZero,
+            Self::Down => RoundingStrategy::ToZero,
+            Self::Ceiling => RoundingStrategy::ToPositiveInfinity,
+            Self::Floor => RoundingStrategy::ToNegativeInfinity,
+        }
+    }
+}
+
+/// The settings a `Decimal` computation is carried out with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalContext {
+    /// The number of digits kept after the decimal point, at most `28`.
+    pub precision: u32,
+    /// How results with more digits than `precision` are rounded.
+    pub rounding: Rounding,
+}
+
+impl Default for DecimalContext {
+    fn default() -> Self {
+        DecimalContext { precision: 16, rounding: Rounding::default() }
+    }
+}
+
+/// An exact base 10 number backend.
+///
+/// Literals are read digit by digit from the expression, so `0.1` is exactly
+/// one tenth, and every result is rounded to the precision of the
+/// `DecimalContext`. Values hold up to 28 significant digits.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::resolve_as;
+/// use rustcalculator::engine::decimal::Decimal;
+///
+/// assert_eq!(resolve_as::<Decimal>("0.1 + 0.2"), Ok("0.3".to_owned()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decimal(pub rust_decimal::Decimal);
+
+impl Decimal {
+    fn rounded(value: Option<rust_decimal::Decimal>, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        let value = value.ok_or(ArithmeticError::Overflow)?;
+
+        Ok(Decimal(value.round_dp_with_strategy(context.precision, context.rounding.strategy())))
+    }
+}
+
+impl Number for Decimal {
+    type Context = DecimalContext;
+
+    fn from_literal(literal: &str, _context: &DecimalContext) -> Option<Self> {
+        let literal = literal.strip_suffix('.').unwrap_or(literal);
+        let literal = match literal.strip_prefix('.') {
+            Some(fraction) => format!("0.{}", fraction),
+            None => literal.to_owned(),
+        };
+
+        rust_decimal::Decimal::from_str_exact(&literal).ok().map(Decimal)
+    }
+
+    /// Scales the mantissa by at most `10^28` at a time, so tiny values
+    /// round to the precision of the context rather than failing.
+    fn from_scientific(mantissa: &str, exponent: i32, context: &DecimalContext) -> Option<Self> {
+        let mut value = Self::from_literal(mantissa, context)?.0;
+        let mut remaining = exponent.unsigned_abs();
+
+        while remaining > 0 && !value.is_zero() {
+            let step = remaining.min(28);
+            let scale = if exponent < 0 {
+                rust_decimal::Decimal::from_i128_with_scale(1, step)
+            } else {
+                rust_decimal::Decimal::from_i128_with_scale(10i128.pow(step), 0)
+            };
+            value = value.checked_mul(scale)?;
+            remaining -= step;
+        }
+
+        Decimal::rounded(Some(value), context).ok()
+    }
+
+    fn add(&self, rhs: &Self, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        Decimal::rounded(self.0.checked_add(rhs.0), context)
+    }
+
+    fn sub(&self, rhs: &Self, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        Decimal::rounded(self.0.checked_sub(rhs.0), context)
+    }
+
+    fn mul(&self, rhs: &Self, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        Decimal::rounded(self.0.checked_mul(rhs.0), context)
+    }
+
+    fn div(&self, rhs: &Self, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        if rhs.0.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Decimal::rounded(self.0.checked_div(rhs.0), context)
+    }
+
+    fn neg(&self, _context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        Ok(Decimal(-self.0))
+    }
+
+    fn rem(&self, rhs: &Self, mode: Remainder, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        if rhs.0.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let remainder = self.0.checked_rem(rhs.0).ok_or(ArithmeticError::Overflow)?;
+        if mode == Remainder::Floored && !remainder.is_zero() && remainder.is_sign_negative() != rhs.0.is_sign_negative() {
+            return Decimal::rounded(remainder.checked_add(rhs.0), context);
+        }
+        Decimal::rounded(Some(remainder), context)
+    }
+
+    fn floor_div(&self, rhs: &Self, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        if rhs.0.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Decimal::rounded(self.0.checked_div(rhs.0).map(|quotient| quotient.floor()), context)
+    }
+
+    /// Whole exponents are computed exactly by repeated squaring, anything
+    /// else goes through `f64`.
+    fn pow(&self, rhs: &Self, context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        if self.0.is_zero() && rhs.0.is_sign_negative() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        if !rhs.0.fract().is_zero() {
+            let base = self.0.to_f64().ok_or(ArithmeticError::Overflow)?;
+            let exponent = rhs.0.to_f64().ok_or(ArithmeticError::Overflow)?;
+            let result = <f64 as Number>::pow(&base, &exponent, &())?;
+
+            return Decimal::rounded(rust_decimal::Decimal::from_f64(result), context);
+        }
+
+        let exponent = rhs.0.abs().to_u64().ok_or(ArithmeticError::Overflow)?;
+        let mut result = Some(rust_decimal::Decimal::ONE);
+        let mut base = Some(self.0);
+        let mut remaining = exponent;
+
+        while remaining > 0 {
+            let factor = base.ok_or(ArithmeticError::Overflow)?;
+            if remaining & 1 == 1 {
+                result = result.and_then(|value| value.checked_mul(factor));
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                base = factor.checked_mul(factor);
+            }
+        }
+
+        if rhs.0.is_sign_negative() {
+            result = result.and_then(|value| rust_decimal::Decimal::ONE.checked_div(value));
+        }
+
+        Decimal::rounded(result, context)
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+
+    fn from_f64(value: f64, context: &DecimalContext) -> Option<Self> {
+        Decimal::rounded(rust_decimal::Decimal::from_f64(value), context).ok()
+    }
+
+    fn is_exact(&self) -> bool {
+        true
+    }
+
+    fn to_integer(&self) -> Option<BigInt> {
+        self.0.fract().is_zero().then(|| self.0.trunc().normalize().to_string().parse().ok()).flatten()
+    }
+
+    /// Rounding functions stay exact.
+    fn call(function: Function, args: &[Self], context: &DecimalContext) -> Result<Self, ArithmeticError> {
+        match function {
+            Function::Floor => Ok(Decimal(args[0].0.floor())),
+            Function::Ceil => Ok(Decimal(args[0].0.ceil())),
+            Function::Round => Ok(Decimal(args[0].0.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero))),
+            _ => functions::approximate(function, args, context),
+        }
+    }
+
+    fn format(&self, _context: &DecimalContext) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.normalize())
+    }
+}
@@ -0,0 +1,347 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+use crate::engine::constants::Constant;
+use crate::engine::error::Error;
+use crate::engine::evaluator::evaluate_value_in;
+use crate::engine::formatter::format_value_with;
+use crate::engine::functions::Function;
+use crate::engine::number::Number;
+use crate::engine::settings::{ImplicitMultiplication, Settings};
+use crate::engine::shunting_yard::to_rpn;
+use crate::engine::span::{Span, Spanned};
+use crate::engine::token::{Operator, Token};
+use crate::engine::tokenizer::tokenize_in;
+use crate::engine::units::Quantity;
+use crate::engine::value::{boolean_literal, Value};
+
+/// The number of previous results an `Environment` keeps.
+pub const HISTORY_LIMIT: usize = 100;
+
+/// The state kept between evaluations: the `Settings` to evaluate with, the
+/// variables and functions defined so far and the previous results.
+///
+/// An expression of the form `name = expression` stores its result under
+/// `name`, and later expressions can use `name` wherever a value is
+/// expected. One of the form `name(x, y) = expression` defines a function
+/// that can be called like the built-in ones. Every result is also
+/// remembered: `ans` is the last one, `ans1` the one before it, `ans2` the
+/// one before that and so on. Comparisons such as `x > 5` produce booleans
+/// and values with units such as `3 ft` produce quantities, which are
+/// remembered and assigned like numbers. The names of `Constant`s, of
+/// built-in functions, `true`, `false` and the `ans` names cannot be
+/// assigned or defined as functions.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::decimal::Decimal;
+/// use rustcalculator::engine::environment::Environment;
+///
+/// let mut environment = Environment::<Decimal>::default();
+///
+/// assert_eq!(environment.resolve("rate = 0.07"), Ok("0.07".to_owned()));
+/// assert_eq!(environment.resolve("1200 * rate"), Ok("84".to_owned()));
+/// assert_eq!(environment.resolve("ans + ans1"), Ok("84.07".to_owned()));
+///
+/// assert_eq!(environment.resolve("f(x, y) = x^2 + y"), Ok("f(x, y) = x^2 + y".to_owned()));
+/// assert_eq!(environment.resolve("f(3, 4)"), Ok("13".to_owned()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Environment<N: Number = f64> {
+    /// The settings every evaluation in this environment uses.
+    pub settings: Settings<N>,
+    variables: BTreeMap<String, Value<N>>,
+    functions: BTreeMap<String, UserFunction<N>>,
+    history: VecDeque<Value<N>>,
+}
+
+/// A function defined with `name(parameters) = body`.
+#[derive(Clone, Debug)]
+pub struct UserFunction<N: Number = f64> {
+    /// The name the function is called with.
+    pub name: String,
+    /// The names the arguments are bound to in the body.
+    pub parameters: Vec<String>,
+    /// The body as it was written.
+    pub body: String,
+    tokens: Vec<Spanned<Token<N>>>,
+}
+
+impl<N: Number> UserFunction<N> {
+    /// Returns the body in reverse polish notation.
+    pub(crate) fn tokens(&self) -> &[Spanned<Token<N>>] {
+        &self.tokens
+    }
+}
+
+impl<N: Number> fmt::Display for UserFunction<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}) = {}", self.name, self.parameters.join(", "), self.body)
+    }
+}
+
+/// What running a line in an `Environment` produced.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome<N = f64> {
+    /// The value of an expression or of a variable assignment.
+    Value(N),
+    /// The result of a comparison or a boolean expression.
+    Boolean(bool),
+    /// The value of an expression with units.
+    Quantity(Quantity<N>),
+    /// The name of the function a definition introduced or replaced.
+    Definition(String),
+}
+
+/// The part of a line before its `=`.
+enum Target {
+    Variable(String),
+    Function(String, Vec<String>),
+}
+
+impl<N: Number> Environment<N> {
+    /// Creates an environment without variables that evaluates with the
+    /// given `settings`.
+    pub fn new(settings: Settings<N>) -> Self {
+        Environment { settings, variables: BTreeMap::new(), functions: BTreeMap::new(), history: VecDeque::new() }
+    }
+
+    /// Returns the value of a variable, if it has been assigned.
+    pub fn get(&self, name: &str) -> Option<&Value<N>> {
+        self.variables.get(name)
+    }
+
+    /// Assigns `value`, a number, a boolean or a quantity, to the variable
+    /// `name`, replacing any previous value.
+    pub fn set(&mut self, name: &str, value: impl Into<Value<N>>) {
+        self.variables.insert(name.to_owned(), value.into());
+    }
+
+    /// Removes a variable.
+    ///
+    /// # Returns
+    /// Returns the value the variable had, if it was assigned.
+    pub fn remove(&mut self, name: &str) -> Option<Value<N>> {
+        self.variables.remove(name)
+    }
+
+    /// Returns every variable with its value, ordered by name.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &Value<N>)> {
+        self.variables.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns a user-defined function, if it has been defined.
+    pub fn function(&self, name: &str) -> Option<&UserFunction<N>> {
+        self.functions.get(name)
+    }
+
+    /// Removes a user-defined function.
+    ///
+    /// # Returns
+    /// Returns the definition that was removed, if there was one.
+    pub fn remove_function(&mut self, name: &str) -> Option<UserFunction<N>> {
+        self.functions.remove(name)
+    }
+
+    /// Returns every user-defined function, ordered by name.
+    pub fn functions(&self) -> impl Iterator<Item = &UserFunction<N>> {
+        self.functions.values()
+    }
+
+    /// Returns a previous result, `0` being the last one.
+    ///
+    /// # Returns
+    /// Returns the value `ans` (for `0`) or `ans1`, `ans2` and so on stand
+    /// for, if there have been that many results.
+    pub fn answer(&self, age: usize) -> Option<&Value<N>> {
+        self.history.get(age)
+    }
+
+    /// Returns the previous results, the last one first.
+    pub fn history(&self) -> impl Iterator<Item = &Value<N>> {
+        self.history.iter()
+    }
+
+    /// Forgets the previous results.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Runs an expression, a variable assignment or a function definition in
+    /// this environment. Values become the new `ans`.
+    ///
+    /// # Returns
+    /// Returns what the line produced, or the `Error` that stopped it from
+    /// being run.
+    pub fn execute(&mut self, expr: &str) -> Result<Outcome<N>, Error> {
+        self.run(expr, true)
+    }
+
+    /// Evaluates an expression or a variable assignment in this environment,
+    /// without formatting the result. The value becomes the new `ans`.
+    ///
+    /// # Returns
+    /// Returns the value of the expression, which for an assignment is the
+    /// value assigned, or the `Error` that stopped it from being evaluated.
+    /// Function definitions have no value and are reported as an
+    /// `Error::InvalidAssignment`; use `execute` to run them.
+    pub fn evaluate(&mut self, expr: &str) -> Result<Value<N>, Error> {
+        match self.run(expr, false)? {
+            Outcome::Value(value) => Ok(Value::Number(value)),
+            Outcome::Boolean(boolean) => Ok(Value::Boolean(boolean)),
+            Outcome::Quantity(quantity) => Ok(Value::Quantity(quantity)),
+            Outcome::Definition(_) => unreachable!("definitions are rejected by run"),
+        }
+    }
+
+    /// Evaluates an expression or a variable assignment in this environment
+    /// to a number. See `evaluate`.
+    ///
+    /// # Returns
+    /// Returns the number, or over the whole expression
+    /// `Error::ExpectedNumber` when it is a comparison or another boolean
+    /// expression and `Error::UnexpectedUnit` when it has a unit.
+    pub fn compute(&mut self, expr: &str) -> Result<N, Error> {
+        match self.evaluate(expr)? {
+            Value::Number(value) => Ok(value),
+            Value::Boolean(_) => Err(Error::ExpectedNumber(Span::new(0, expr.len()))),
+            Value::Quantity(_) => Err(Error::UnexpectedUnit(Span::new(0, expr.len()))),
+        }
+    }
+
+    /// Runs an expression, a variable assignment or a function definition in
+    /// this environment and formats the result with its settings. A
+    /// definition is formatted as written.
+    pub fn resolve(&mut self, expr: &str) -> Result<String, Error> {
+        match self.execute(expr)? {
+            Outcome::Value(value) => Ok(format_value_with(&Value::Number(value), &self.settings)),
+            Outcome::Boolean(boolean) => Ok(format_value_with(&Value::Boolean(boolean), &self.settings)),
+            Outcome::Quantity(quantity) => Ok(format_value_with(&Value::Quantity(quantity), &self.settings)),
+            Outcome::Definition(name) => Ok(self.functions[&name].to_string()),
+        }
+    }
+
+    /// Returns `true` when `name` followed by `(` is multiplied rather than
+    /// called, that is when it names a constant, a variable or a previous
+    /// result but no function.
+    fn multiplies(&self, name: &str) -> bool {
+        let is_value = Constant::from_name(name).is_some() || self.get(name).is_some() || answer_age(name).is_some();
+
+        is_value && Function::from_name(name).is_none() && self.function(name).is_none()
+    }
+
+    fn run(&mut self, expr: &str, allow_definitions: bool) -> Result<Outcome<N>, Error> {
+        let mut tokens = tokenize_in::<N>(expr, &self.settings, &|name| self.multiplies(name))?;
+        let assign = assignment_span(&tokens);
+        let target = split_assignment(&mut tokens)?;
+
+        if let Some(Target::Function(name, parameters)) = target {
+            if !allow_definitions {
+                return Err(Error::InvalidAssignment(assign.unwrap_or_default()));
+            }
+            shadow_units(&mut tokens, &parameters, self.settings.implicit_multiplication);
+
+            let body = match (tokens.first(), tokens.last()) {
+                (Some(first), Some(last)) => expr[first.span.to(last.span).range()].to_owned(),
+                _ => String::new(),
+            };
+            let function = UserFunction { name: name.clone(), parameters, body, tokens: to_rpn(tokens) };
+            self.functions.insert(name.clone(), function);
+
+            return Ok(Outcome::Definition(name));
+        }
+
+        let value = evaluate_value_in(to_rpn(tokens), self)?;
+        if let Some(Target::Variable(name)) = target {
+            self.set(&name, value.clone());
+        }
+
+        self.history.push_front(value.clone());
+        self.history.truncate(HISTORY_LIMIT);
+
+        Ok(match value {
+            Value::Number(value) => Outcome::Value(value),
+            Value::Boolean(boolean) => Outcome::Boolean(boolean),
+            Value::Quantity(quantity) => Outcome::Quantity(quantity),
+        })
+    }
+}
+
+impl<N: Number> Default for Environment<N> {
+    fn default() -> Self {
+        Environment::new(Settings::default())
+    }
+}
+
+/// Removes the `name =` or `name(parameters) =` in front of an assignment.
+///
+/// # Returns
+/// Returns what is assigned to, or `None` for a plain expression.
+fn split_assignment<N>(tokens: &mut Vec<Spanned<Token<N>>>) -> Result<Option<Target>, Error> {
+    let Some(end) = tokens.iter().position(|token| matches!(token.value, Token::Assign)) else {
+        return Ok(None);
+    };
+
+    let mut head = tokens.drain(..=end);
+    let target = head.next().unwrap();
+    match target.value {
+        Token::Identifier(name) if is_assignable(&name) => Ok(Some(Target::Variable(name))),
+        Token::Function(name) if is_assignable(&name) => {
+            let mut parameters: Vec<String> = Vec::new();
+            for token in head {
+                if let Token::Identifier(parameter) = token.value {
+                    if parameters.contains(&parameter) {
+                        return Err(Error::InvalidAssignment(token.span));
+                    }
+                    parameters.push(parameter);
+                }
+            }
+            Ok(Some(Target::Function(name, parameters)))
+        },
+        _ => Err(Error::InvalidAssignment(target.span)),
+    }
+}
+
+/// Turns the multiplications the tokenizer bound tightly before a unit back
+/// into ordinary ones where the name is a parameter, since parameters shadow
+/// units.
+fn shadow_units<N>(tokens: &mut [Spanned<Token<N>>], parameters: &[String], precedence: ImplicitMultiplication) {
+    let operator = match precedence {
+        ImplicitMultiplication::Ordinary => Operator::Mul,
+        ImplicitMultiplication::Tight => Operator::ImplicitMul,
+    };
+
+    for i in 1..tokens.len() {
+        let is_parameter = matches!(&tokens[i].value, Token::Identifier(name) if parameters.contains(name));
+        if is_parameter && matches!(tokens[i - 1].value, Token::Operator(Operator::ImplicitMul)) {
+            tokens[i - 1].value = Token::Operator(operator);
+        }
+    }
+}
+
+/// Returns `false` for the names a variable or function cannot take: those
+/// of `Constant`s, of built-in functions, `true`, `false` and the `ans`
+/// names.
+fn is_assignable(name: &str) -> bool {
+    Constant::from_name(name).is_none()
+        && Function::from_name(name).is_none()
+        && boolean_literal(name).is_none()
+        && answer_age(name).is_none()
+}
+
+/// Reads the names `ans`, `ans1`, `ans2` and so on.
+///
+/// # Returns
+/// Returns how many results back the name refers to, `0` for `ans`.
+pub fn answer_age(name: &str) -> Option<usize> {
+    match name.strip_prefix("ans")? {
+        "" => Some(0),
+        digits if digits.bytes().all(|b| b.is_ascii_digit()) => digits.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the span of the `=` of an assignment, if the tokens hold one.
+fn assignment_span<N>(tokens: &[Spanned<Token<N>>]) -> Option<Span> {
+    tokens.iter().find(|token| matches!(token.value, Token::Assign)).map(|token| token.span)
+}
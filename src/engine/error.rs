@@ -0,0 +1,142 @@
+use std::fmt;
+
+use crate::engine::number::ArithmeticError;
+use crate::engine::span::Span;
+use crate::engine::token::Operator;
+
+/// Errors reported by the engine while reading or evaluating an expression.
+///
+/// Every variant carries the `Span` of the part of the expression it refers
+/// to, so callers can point the user at the offending characters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A character that is not part of the expression language.
+    BadToken(char, Span),
+    /// A number literal the backend could not read, as in `1.2.3`.
+    InvalidNumber(Span),
+    /// A `(` without a matching `)` or the other way around.
+    MismatchedParens(Span),
+    /// An operator without a value on its left, as in `*3` or `2+*3`.
+    MissingOperand(Operator, Span),
+    /// An operator without a value on its right, as in `2+` or `(2+)`.
+    TrailingOperator(Operator, Span),
+    /// Two values without an operator between them, as in `2 3`. The span
+    /// points at the second value.
+    MissingOperator(Span),
+    /// Nothing to evaluate, either the whole expression or a pair of `()`.
+    EmptyExpression(Span),
+    /// A division whose right-hand side evaluated to zero. The span points
+    /// at the divisor.
+    DivisionByZero(Span),
+    /// A result too large to be represented. The span points at the
+    /// operation that produced it.
+    Overflow(Span),
+    /// An operation without a result for its operands, as in `(-8) ^ 0.5`.
+    Undefined(Span),
+    /// A name that is neither an assigned variable nor a constant.
+    UnknownIdentifier(String, Span),
+    /// A call to a function that is not known to the engine.
+    UnknownFunction(String, Span),
+    /// A call to a function with a number of arguments it does not accept.
+    WrongArgumentCount(String, usize, Span),
+    /// An `=` that does not follow a single variable name or a function
+    /// head at the start of the expression, or an attempt to assign to a
+    /// constant or a built-in function.
+    InvalidAssignment(Span),
+    /// An operation that only works on integers, such as `&` or `<<`, was
+    /// given a value with a fractional part.
+    NotAnInteger(Span),
+    /// A boolean, such as the result of a comparison, where a number is
+    /// needed, as in `(1 < 2) + 1`.
+    ExpectedNumber(Span),
+    /// A number where a boolean is needed, as in `not 1` or `if(1, 2, 3)`.
+    ExpectedBoolean(Span),
+    /// Two quantities of different dimensions added, compared or converted
+    /// into one another, as in `1 m + 1 s` or `1 kg to m`.
+    IncompatibleUnits(Span),
+    /// A quantity with a unit where a plain number is needed, as in
+    /// `sqrt(2 m)` or `2 ^ (1 s)`.
+    UnexpectedUnit(Span),
+    /// A conversion to something other than a bare unit, as in
+    /// `60 mph to 2 km/h`. The span points at the target.
+    ExpectedUnit(Span),
+    /// A user-defined function that kept calling itself past the depth
+    /// limit, as in `f(x) = f(x)`. The span points at the outermost call.
+    RecursionLimit(String, Span),
+    /// An error raised in the body of a user-defined function. The span
+    /// points at the call.
+    InFunction(String, Box<Error>, Span),
+}
+
+impl Error {
+    /// Returns the part of the expression the error refers to.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::BadToken(_, span)
+            | Self::InvalidNumber(span)
+            | Self::MismatchedParens(span)
+            | Self::MissingOperand(_, span)
+            | Self::TrailingOperator(_, span)
+            | Self::MissingOperator(span)
+            | Self::EmptyExpression(span)
+            | Self::DivisionByZero(span)
+            | Self::Overflow(span)
+            | Self::Undefined(span)
+            | Self::UnknownIdentifier(_, span)
+            | Self::UnknownFunction(_, span)
+            | Self::WrongArgumentCount(_, _, span)
+            | Self::InvalidAssignment(span)
+            | Self::NotAnInteger(span)
+            | Self::ExpectedNumber(span)
+            | Self::ExpectedBoolean(span)
+            | Self::IncompatibleUnits(span)
+            | Self::UnexpectedUnit(span)
+            | Self::ExpectedUnit(span)
+            | Self::RecursionLimit(_, span)
+            | Self::InFunction(_, _, span) => *span,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadToken(c, _) => write!(f, "unexpected character '{}'", c),
+            Self::InvalidNumber(_) => write!(f, "invalid number"),
+            Self::MismatchedParens(_) => write!(f, "mismatched parentheses"),
+            Self::MissingOperand(op, _) => write!(f, "missing operand before '{}'", op),
+            Self::TrailingOperator(op, _) => write!(f, "missing operand after '{}'", op),
+            Self::MissingOperator(_) => write!(f, "missing operator"),
+            Self::EmptyExpression(_) => write!(f, "empty expression"),
+            Self::DivisionByZero(_) => write!(f, "division by zero"),
+            Self::Overflow(_) => write!(f, "overflow"),
+            Self::Undefined(_) => write!(f, "undefined result"),
+            Self::UnknownIdentifier(name, _) => write!(f, "undefined variable '{}'", name),
+            Self::UnknownFunction(name, _) => write!(f, "unknown function '{}'", name),
+            Self::WrongArgumentCount(name, count, _) => write!(f, "'{}' does not take {} arguments", name, count),
+            Self::InvalidAssignment(_) => write!(f, "can only assign to a new variable or function name"),
+            Self::NotAnInteger(_) => write!(f, "expected an integer"),
+            Self::ExpectedNumber(_) => write!(f, "expected a number, not a boolean"),
+            Self::ExpectedBoolean(_) => write!(f, "expected a boolean, not a number"),
+            Self::IncompatibleUnits(_) => write!(f, "incompatible units"),
+            Self::UnexpectedUnit(_) => write!(f, "expected a number without a unit"),
+            Self::ExpectedUnit(_) => write!(f, "can only convert to a unit"),
+            Self::RecursionLimit(name, _) => write!(f, "'{}' calls itself too deeply", name),
+            Self::InFunction(name, error, _) => write!(f, "in '{}': {}", name, error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    /// Attaches a span to an error raised by a `Number` backend.
+    pub fn arithmetic(error: ArithmeticError, span: Span) -> Self {
+        match error {
+            ArithmeticError::DivisionByZero => Self::DivisionByZero(span),
+            ArithmeticError::Overflow => Self::Overflow(span),
+            ArithmeticError::Undefined => Self::Undefined(span),
+            ArithmeticError::NotAnInteger => Self::NotAnInteger(span),
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use std::cmp::Ordering;
+
+use crate::engine::bitwise;
+use crate::engine::constants::Constant;
+use crate::engine::environment::{answer_age, Environment};
+use crate::engine::error::Error;
+use crate::engine::functions::{self, Function};
+use crate::engine::number::{ArithmeticError, Number};
+use crate::engine::settings::Settings;
+use crate::engine::span::{Span, Spanned};
+use crate::engine::token::{Operator, Token};
+use crate::engine::units::{Quantity, Unit};
+use crate::engine::value::{boolean_literal, Value};
+
+/// How many calls to user-defined functions can be nested before the
+/// evaluation gives up with `Error::RecursionLimit`.
+pub const MAX_CALL_DEPTH: usize = 64;
+
+/// The arguments of the user-defined function being evaluated.
+struct Scope<'a, N> {
+    parameters: &'a [String],
+    arguments: &'a [Value<N>],
+    depth: usize,
+}
+
+/// Evaluates a list of tokens in reverse polish notation, as produced by
+/// `to_rpn`.
+///
+/// Intermediate values remember the span of the sub-expression they came
+/// from, so errors point at the operands involved.
+///
+/// # Returns
+/// Returns the single value the tokens reduce to, or an `Error` when an
+/// operator lacks operands, a name or function is unknown or called with
+/// the wrong number of arguments, units do not match, a division by zero
+/// happens or a result overflows.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{evaluate, to_rpn, tokenize, Error, Span};
+///
+/// let value = evaluate(to_rpn(tokenize::<f64>("(1 + 2) * 3").unwrap()));
+/// assert_eq!(value, Ok(9.0));
+///
+/// let value = evaluate(to_rpn(tokenize::<f64>("1 / (2 - 2)").unwrap()));
+/// assert_eq!(value, Err(Error::DivisionByZero(Span::new(5, 10))));
+/// ```
+pub fn evaluate<N: Number>(tokens: Vec<Spanned<Token<N>>>) -> Result<N, Error> {
+    evaluate_with(tokens, &Settings::default())
+}
+
+/// Evaluates a list of tokens in reverse polish notation, carrying out every
+/// operation with the given `settings`. See `evaluate`.
+pub fn evaluate_with<N: Number>(tokens: Vec<Spanned<Token<N>>>, settings: &Settings<N>) -> Result<N, Error> {
+    evaluate_in(tokens, &Environment::new(settings.clone()))
+}
+
+/// Evaluates a list of tokens in reverse polish notation in the given
+/// `environment`, whose variables and previous results are looked up before
+/// the constants and whose functions are called before the built-in ones.
+/// See `evaluate`.
+///
+/// # Returns
+/// Returns the number the tokens reduce to, `Error::ExpectedNumber` when
+/// they reduce to a boolean or `Error::UnexpectedUnit` when they reduce to
+/// a quantity; use `evaluate_value_in` to accept all of them.
+pub fn evaluate_in<N: Number>(tokens: Vec<Spanned<Token<N>>>, environment: &Environment<N>) -> Result<N, Error> {
+    let scope = Scope { parameters: &[], arguments: &[], depth: 0 };
+
+    number(reduce(&tokens, environment, &scope)?).map(|number| number.value)
+}
+
+/// Evaluates a list of tokens in reverse polish notation in the given
+/// `environment` to a number, to a boolean for comparisons such as `x > 5`
+/// and the boolean operators, or to a quantity for values with units. See
+/// `evaluate_in`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{evaluate_value_in, to_rpn, tokenize, Environment, Value};
+///
+/// let tokens = to_rpn(tokenize::<f64>("2 + 2 == 4 and not 1 > 2").unwrap());
+///
+/// assert_eq!(evaluate_value_in(tokens, &Environment::default()), Ok(Value::Boolean(true)));
+/// ```
+pub fn evaluate_value_in<N: Number>(tokens: Vec<Spanned<Token<N>>>, environment: &Environment<N>) -> Result<Value<N>, Error> {
+    let scope = Scope { parameters: &[], arguments: &[], depth: 0 };
+
+    reduce(&tokens, environment, &scope).map(|value| value.value)
+}
+
+fn reduce<N: Number>(tokens: &[Spanned<Token<N>>], environment: &Environment<N>, scope: &Scope<N>) -> Result<Spanned<Value<N>>, Error> {
+    let settings = &environment.settings;
+
+    let mut stack: Vec<Spanned<Value<N>>> = Vec::new();
+    let mut index = 0;
+    while let Some(token) = tokens.get(index) {
+        index += 1;
+        match &token.value {
+            Token::Number(num) => stack.push(Spanned::new(Value::Number(num.clone()), token.span)),
+            Token::Identifier(name) => stack.push(lookup(name, token.span, environment, scope)?),
+            Token::Operator(op) if op.is_unary() => {
+                let operand = stack.pop().ok_or(Error::TrailingOperator(*op, token.span))?;
+                stack.push(apply_unary(*op, token.span, operand, &settings.context)?);
+            },
+            Token::Operator(op) if op.is_postfix() => {
+                let operand = number(stack.pop().ok_or(Error::MissingOperand(*op, token.span))?)?;
+                // A percentage added to or subtracted from a value is taken
+                // of that value, which sits right below it on the stack.
+                let base = match tokens.get(index).map(|next| &next.value) {
+                    Some(Token::Operator(Operator::Add | Operator::Sub)) => stack.last().and_then(|base| base.value.number()),
+                    _ => None,
+                };
+                stack.push(apply_postfix(*op, token.span, operand, base, &settings.context)?);
+            },
+            Token::Operator(op) => {
+                let right = stack.pop().ok_or(Error::MissingOperand(*op, token.span))?;
+                let left = stack.pop().ok_or(Error::MissingOperand(*op, token.span))?;
+                stack.push(apply(*op, left, right, settings)?);
+            },
+            Token::Call(name, count) => {
+                if stack.len() < *count {
+                    return Err(Error::WrongArgumentCount(name.clone(), stack.len(), token.span));
+                }
+                let args = stack.split_off(stack.len() - count);
+                stack.push(call(name, token.span, args, environment, scope)?);
+            },
+            Token::JumpUnless(target) => {
+                let condition = stack.pop().ok_or(Error::EmptyExpression(token.span))?;
+                if !boolean(condition)? {
+                    index = *target;
+                }
+            },
+            Token::Jump(target) => index = *target,
+            _ => {}
+        }
+    }
+
+    match stack.len() {
+        0 => Err(Error::EmptyExpression(Span::default())),
+        1 => Ok(stack.remove(0)),
+        _ => Err(Error::MissingOperator(stack[1].span)),
+    }
+}
+
+fn apply<N: Number>(op: Operator, left: Spanned<Value<N>>, right: Spanned<Value<N>>, settings: &Settings<N>) -> Result<Spanned<Value<N>>, Error> {
+    let span = left.span.to(right.span);
+
+    match (op, &left.value, &right.value) {
+        (Operator::Equal | Operator::NotEqual, Value::Boolean(a), Value::Boolean(b)) => {
+            return Ok(Spanned::new(Value::Boolean((a == b) == (op == Operator::Equal)), span));
+        },
+        (Operator::And | Operator::Or, _, _) => {
+            let (left, right) = (boolean(left)?, boolean(right)?);
+            let result = if op == Operator::And { left && right } else { left || right };
+            return Ok(Spanned::new(Value::Boolean(result), span));
+        },
+        (Operator::To, _, _) | (_, Value::Quantity(_), _) | (_, _, Value::Quantity(_)) => {
+            return apply_units(op, left, right, &settings.context);
+        },
+        _ => {},
+    }
+
+    let (left, right) = (number(left)?, number(right)?);
+    if comparison(op).is_some() {
+        return compare(op, &left.value, &right.value, span);
+    }
+
+    let context = &settings.context;
+    let result = match op {
+        Operator::Add => left.value.add(&right.value, context),
+        Operator::Sub => left.value.sub(&right.value, context),
+        Operator::Mul | Operator::ImplicitMul => left.value.mul(&right.value, context),
+        Operator::Div => left.value.div(&right.value, context),
+        Operator::Rem => left.value.rem(&right.value, settings.remainder, context),
+        Operator::FloorDiv => left.value.floor_div(&right.value, context),
+        Operator::Pow => left.value.pow(&right.value, context),
+        Operator::BitAnd | Operator::BitOr | Operator::BitXor | Operator::Shl | Operator::Shr => {
+            bitwise::apply(op, &left.value, &right.value, context)
+        },
+        Operator::Neg | Operator::Pos | Operator::BitNot | Operator::Not => unreachable!("prefix operators are applied by apply_unary"),
+        Operator::Percent | Operator::Factorial => unreachable!("postfix operators are applied by apply_postfix"),
+        _ => unreachable!("{} is applied above", op),
+    };
+
+    result
+        .map(|value| Spanned::new(Value::Number(value), span))
+        .map_err(|error| match (op, error) {
+            (Operator::Div | Operator::Rem | Operator::FloorDiv, ArithmeticError::DivisionByZero) => {
+                Error::arithmetic(error, right.span)
+            },
+            _ => Error::arithmetic(error, span),
+        })
+}
+
+/// Applies an operator to two values of which at least one has a unit, or
+/// converts the left-hand side to the unit of the right-hand side with `to`.
+/// Plain numbers take part as quantities without a unit.
+fn apply_units<N: Number>(op: Operator, left: Spanned<Value<N>>, right: Spanned<Value<N>>, context: &N::Context) -> Result<Spanned<Value<N>>, Error> {
+    let span = left.span.to(right.span);
+    let (left_span, right_span) = (left.span, right.span);
+    let (left, right) = (quantity(left, context)?, quantity(right, context)?);
+    let same = left.unit.dimension == right.unit.dimension;
+    let arithmetic = |error| Error::arithmetic(error, span);
+
+    let result = match op {
+        Operator::Add | Operator::Sub | Operator::To | Operator::Less | Operator::LessEqual | Operator::Greater
+        | Operator::GreaterEqual | Operator::Equal | Operator::NotEqual if !same => {
+            return Err(Error::IncompatibleUnits(span));
+        },
+        Operator::Add | Operator::Sub => {
+            let rhs = right.value_in(&left.unit, context).map_err(arithmetic)?;
+            let value = if op == Operator::Add { left.value.add(&rhs, context) } else { left.value.sub(&rhs, context) };
+            Value::Quantity(Quantity::new(value.map_err(arithmetic)?, left.unit))
+        },
+        Operator::To => {
+            let one = Unit::one(context).ok_or(Error::Overflow(span))?;
+            if right.value != one.factor {
+                return Err(Error::ExpectedUnit(right_span));
+            }
+            let value = left.value_in(&right.unit, context).map_err(arithmetic)?;
+            Value::Quantity(Quantity::new(value, right.unit))
+        },
+        Operator::Mul | Operator::ImplicitMul => left.mul(&right, context).map_err(arithmetic)?,
+        Operator::Div => left.div(&right, context).map_err(|error| match error {
+            ArithmeticError::DivisionByZero => Error::arithmetic(error, right_span),
+            _ => Error::arithmetic(error, span),
+        })?,
+        Operator::Pow if right.unit.dimension.is_dimensionless() => {
+            let exponent = right.value.to_integer().and_then(|exponent| i32::try_from(exponent).ok());
+            left.powi(exponent.ok_or(Error::NotAnInteger(right_span))?, context).map_err(arithmetic)?
+        },
+        _ if comparison(op).is_some() => {
+            let one = Unit::one(context).ok_or(Error::Overflow(span))?;
+            let (left, right) = (left.value_in(&one, context), right.value_in(&one, context));
+            return compare(op, &left.map_err(arithmetic)?, &right.map_err(arithmetic)?, span);
+        },
+        _ if left.unit.dimension.is_dimensionless() => return Err(Error::UnexpectedUnit(right_span)),
+        _ => return Err(Error::UnexpectedUnit(left_span)),
+    };
+
+    Ok(Spanned::new(result, span))
+}
+
+/// Compares two numbers with a comparison operator.
+fn compare<N: Number>(op: Operator, left: &N, right: &N, span: Span) -> Result<Spanned<Value<N>>, Error> {
+    let wanted = comparison(op).expect("a comparison operator");
+    let ordering = match left.compare(right) {
+        Some(ordering) => ordering,
+        // Numbers without an order, such as complex ones, can still be
+        // told apart.
+        None if matches!(op, Operator::Equal | Operator::NotEqual) => {
+            if left == right { Ordering::Equal } else { Ordering::Less }
+        },
+        None => return Err(Error::Undefined(span)),
+    };
+
+    Ok(Spanned::new(Value::Boolean(wanted(ordering)), span))
+}
+
+/// Returns the test a comparison operator makes of the ordering of its
+/// operands, or `None` for other operators.
+fn comparison(op: Operator) -> Option<fn(Ordering) -> bool> {
+    match op {
+        Operator::Less => Some(Ordering::is_lt),
+        Operator::LessEqual => Some(Ordering::is_le),
+        Operator::Greater => Some(Ordering::is_gt),
+        Operator::GreaterEqual => Some(Ordering::is_ge),
+        Operator::Equal => Some(Ordering::is_eq),
+        Operator::NotEqual => Some(Ordering::is_ne),
+        _ => None,
+    }
+}
+
+fn apply_unary<N: Number>(op: Operator, span: Span, operand: Spanned<Value<N>>, context: &N::Context) -> Result<Spanned<Value<N>>, Error> {
+    let span = span.to(operand.span);
+    if op == Operator::Not {
+        return Ok(Spanned::new(Value::Boolean(!boolean(operand)?), span));
+    }
+    if let (Operator::Neg | Operator::Pos, Value::Quantity(quantity)) = (op, &operand.value) {
+        let value = match op {
+            Operator::Neg => quantity.value.neg(context).map_err(|error| Error::arithmetic(error, span))?,
+            _ => quantity.value.clone(),
+        };
+        return Ok(Spanned::new(Value::Quantity(Quantity::new(value, quantity.unit.clone())), span));
+    }
+
+    let operand = number(operand)?;
+    let result = match op {
+        Operator::Neg => operand.value.neg(context),
+        Operator::BitNot => bitwise::not(&operand.value, context),
+        _ => Ok(operand.value),
+    };
+
+    result
+        .map(|value| Spanned::new(Value::Number(value), span))
+        .map_err(|error| Error::arithmetic(error, span))
+}
+
+fn apply_postfix<N: Number>(op: Operator, span: Span, operand: Spanned<N>, base: Option<&N>, context: &N::Context) -> Result<Spanned<Value<N>>, Error> {
+    let span = operand.span.to(span);
+    let result = match op {
+        Operator::Percent => {
+            let hundred = N::from_literal("100", context).ok_or(Error::Overflow(span))?;
+            let fraction = operand.value.div(&hundred, context);
+            match base {
+                Some(base) => fraction.and_then(|fraction| base.mul(&fraction, context)),
+                None => fraction,
+            }
+        },
+        Operator::Factorial => functions::factorial(&operand.value, context),
+        _ => Ok(operand.value),
+    };
+
+    result
+        .map(|value| Spanned::new(Value::Number(value), span))
+        .map_err(|error| Error::arithmetic(error, span))
+}
+
+fn lookup<N: Number>(name: &str, span: Span, environment: &Environment<N>, scope: &Scope<N>) -> Result<Spanned<Value<N>>, Error> {
+    if let Some(index) = scope.parameters.iter().position(|parameter| parameter == name) {
+        return Ok(Spanned::new(scope.arguments[index].clone(), span));
+    }
+
+    if let Some(value) = environment.get(name) {
+        return Ok(Spanned::new(value.clone(), span));
+    }
+
+    if let Some(value) = answer_age(name).and_then(|age| environment.answer(age)) {
+        return Ok(Spanned::new(value.clone(), span));
+    }
+
+    if name == "i" {
+        if let Some(unit) = N::imaginary_unit(&environment.settings.context) {
+            return Ok(Spanned::new(Value::Number(unit), span));
+        }
+    }
+
+    if let Some(boolean) = boolean_literal(name) {
+        return Ok(Spanned::new(Value::Boolean(boolean), span));
+    }
+
+    let context = &environment.settings.context;
+    if let Some(constant) = Constant::from_name(name) {
+        let value = N::from_f64(constant.value(), context).ok_or(Error::Overflow(span))?;
+        return Ok(Spanned::new(Value::Number(value), span));
+    }
+
+    let unit = Unit::from_name(name, context).ok_or_else(|| Error::UnknownIdentifier(name.to_owned(), span))?;
+    let one = N::from_literal("1", context).ok_or(Error::Overflow(span))?;
+
+    Ok(Spanned::new(Value::Quantity(Quantity::new(one, unit)), span))
+}
+
+fn call<N: Number>(name: &str, span: Span, args: Vec<Spanned<Value<N>>>, environment: &Environment<N>, scope: &Scope<N>) -> Result<Spanned<Value<N>>, Error> {
+    if let Some(function) = environment.function(name) {
+        if function.parameters.len() != args.len() {
+            return Err(Error::WrongArgumentCount(name.to_owned(), args.len(), span));
+        }
+        if scope.depth >= MAX_CALL_DEPTH {
+            return Err(Error::RecursionLimit(name.to_owned(), span));
+        }
+
+        let values: Vec<Value<N>> = args.into_iter().map(|arg| arg.value).collect();
+        let inner = Scope { parameters: &function.parameters, arguments: &values, depth: scope.depth + 1 };
+        return reduce(function.tokens(), environment, &inner)
+            .map(|value| Spanned::new(value.value, span))
+            .map_err(|error| at_call(error, name, span));
+    }
+
+    let function = Function::from_name(name).ok_or_else(|| Error::UnknownFunction(name.to_owned(), span))?;
+    if !function.accepts(args.len()) {
+        return Err(Error::WrongArgumentCount(name.to_owned(), args.len(), span));
+    }
+
+    let values = numbers(args)?;
+
+    N::call(function, &values, &environment.settings.context)
+        .map(|value| Spanned::new(Value::Number(value), span))
+        .map_err(|error| Error::arithmetic(error, span))
+}
+
+/// Takes the number out of a value, failing on a boolean or a quantity.
+fn number<N>(value: Spanned<Value<N>>) -> Result<Spanned<N>, Error> {
+    match value.value {
+        Value::Number(number) => Ok(Spanned::new(number, value.span)),
+        Value::Boolean(_) => Err(Error::ExpectedNumber(value.span)),
+        Value::Quantity(_) => Err(Error::UnexpectedUnit(value.span)),
+    }
+}
+
+/// Takes the quantity out of a value, reading a number as a quantity
+/// without a unit and failing on a boolean.
+fn quantity<N: Number>(value: Spanned<Value<N>>, context: &N::Context) -> Result<Quantity<N>, Error> {
+    match value.value {
+        Value::Quantity(quantity) => Ok(quantity),
+        Value::Number(number) => Ok(Quantity::new(number, Unit::one(context).ok_or(Error::Overflow(value.span))?)),
+        Value::Boolean(_) => Err(Error::ExpectedNumber(value.span)),
+    }
+}
+
+/// Takes the numbers out of the arguments of a call, failing on a boolean
+/// or a quantity.
+fn numbers<N>(args: Vec<Spanned<Value<N>>>) -> Result<Vec<N>, Error> {
+    args.into_iter().map(|arg| number(arg).map(|arg| arg.value)).collect()
+}
+
+/// Takes the boolean out of a value, failing on a number.
+fn boolean<N>(value: Spanned<Value<N>>) -> Result<bool, Error> {
+    match value.value {
+        Value::Boolean(boolean) => Ok(boolean),
+        Value::Number(_) | Value::Quantity(_) => Err(Error::ExpectedBoolean(value.span)),
+    }
+}
+
+/// Moves an error raised in the body of `name` to the call, so its span
+/// refers to the expression being evaluated rather than to the body.
+fn at_call(error: Error, name: &str, span: Span) -> Error {
+    match error {
+        Error::RecursionLimit(name, _) => Error::RecursionLimit(name, span),
+        Error::InFunction(name, error, _) => Error::InFunction(name, error, span),
+        error => Error::InFunction(name.to_owned(), Box::new(error), span),
+    }
+}
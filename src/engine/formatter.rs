@@ -0,0 +1,105 @@
+use crate::engine::number::Number;
+use crate::engine::settings::{Notation, Settings};
+use crate::engine::value::Value;
+
+/// Formats a computed value for display, as described by its backend.
+///
+/// # Returns
+/// Returns the value as a `String`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::format_result;
+///
+/// assert_eq!(format_result(&4.0), "4");
+/// assert_eq!(format_result(&6.3), "6.3");
+/// ```
+pub fn format_result<N: Number>(result: &N) -> String {
+    format_result_with(result, &Settings::default())
+}
+
+/// Formats a computed value for display with the given `settings`. Results
+/// whose magnitude reaches `Settings::notation_threshold` are written in
+/// `Settings::notation`. See `format_result`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{format_result_with, Notation, Settings};
+///
+/// let settings = Settings::<f64> { notation: Notation::Engineering, notation_threshold: 3, ..Default::default() };
+///
+/// assert_eq!(format_result_with(&47000.0, &settings), "47e3");
+/// assert_eq!(format_result_with(&0.00022, &settings), "220e-6");
+/// assert_eq!(format_result_with(&12.5, &settings), "12.5");
+/// ```
+pub fn format_result_with<N: Number>(result: &N, settings: &Settings<N>) -> String {
+    let notation = match settings.notation {
+        Notation::Plain => return result.format(&settings.context),
+        Notation::Auto if result.is_exact() => return result.format(&settings.context),
+        Notation::Auto => Notation::Scientific,
+        notation => notation,
+    };
+
+    match result.to_f64() {
+        Some(value) if reaches_threshold(value, settings.notation_threshold) => {
+            format_exponent(value, notation)
+        },
+        _ => result.format(&settings.context),
+    }
+}
+
+/// Formats a number with `format_result_with`, a boolean as `true` or
+/// `false`, or a quantity as its number followed by its unit, as in `3 ft`,
+/// or alone when the unit is dimensionless and has no name.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{format_value_with, Settings, Value};
+///
+/// assert_eq!(format_value_with(&Value::Number(2.5), &Settings::default()), "2.5");
+/// assert_eq!(format_value_with::<f64>(&Value::Boolean(true), &Settings::default()), "true");
+/// ```
+pub fn format_value_with<N: Number>(value: &Value<N>, settings: &Settings<N>) -> String {
+    match value {
+        Value::Number(number) => format_result_with(number, settings),
+        Value::Boolean(boolean) => boolean.to_string(),
+        Value::Quantity(quantity) => {
+            let value = format_result_with(&quantity.value, settings);
+            match quantity.unit.to_string() {
+                unit if unit.is_empty() => value,
+                unit => format!("{} {}", value, unit),
+            }
+        },
+    }
+}
+
+fn reaches_threshold(value: f64, threshold: i32) -> bool {
+    let magnitude = value.abs();
+    let limit = 10f64.powi(threshold);
+
+    magnitude >= limit || (magnitude != 0.0 && magnitude < 1.0 / limit)
+}
+
+/// Writes a value as a mantissa and a power of ten, moving the decimal point
+/// of the shortest scientific form so engineering exponents stay exact.
+fn format_exponent(value: f64, notation: Notation) -> String {
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+
+    let shift = match notation {
+        Notation::Engineering => exponent.rem_euclid(3),
+        _ => 0,
+    };
+    let point = 1 + shift as usize;
+    let digits = format!("{:0<width$}", digits, width = point);
+    let (whole, fraction) = digits.split_at(point);
+
+    let sign = if value < 0.0 { "-" } else { "" };
+    if fraction.is_empty() {
+        format!("{}{}e{}", sign, whole, exponent - shift)
+    } else {
+        format!("{}{}.{}e{}", sign, whole, fraction, exponent - shift)
+    }
+}
@@ -0,0 +1,344 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive};
+
+use crate::engine::number::{ArithmeticError, Number};
+
+/// A function of the built-in library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Function {
+    Sqrt,
+    Abs,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    /// The natural logarithm.
+    Ln,
+    Log10,
+    /// `log(x)` is the base 10 logarithm, `log(x, base)` takes any base.
+    Log,
+    Exp,
+    Floor,
+    Ceil,
+    /// Rounds half away from zero.
+    Round,
+    Min,
+    Max,
+    /// The number of ways to choose `r` items out of `n`, written
+    /// `nCr(n, r)`.
+    Ncr,
+    /// The number of ordered arrangements of `r` items out of `n`, written
+    /// `nPr(n, r)`.
+    Npr,
+    /// The gamma function, `gamma(x + 1)` being `x!`.
+    Gamma,
+    /// The angle of a complex number, `0` or `π` for real ones.
+    Arg,
+    /// The complex conjugate.
+    Conj,
+    /// The real part.
+    Re,
+    /// The imaginary part, `0` for real numbers.
+    Im,
+    /// `if(condition, a, b)` is `a` when the condition is true and `b`
+    /// otherwise. Only the chosen branch is evaluated, which `to_rpn` sets
+    /// up with jumps, so recursive functions can use it to stop.
+    If,
+}
+
+/// The largest `n` for which `n!` is computed, and the most factors `nPr`
+/// and `nCr` multiply; larger ones are reported as an overflow rather than
+/// left running.
+pub const MAX_FACTORIAL: u64 = 10_000;
+
+impl Function {
+    /// Looks up a built-in function by the name used in expressions.
+    ///
+    /// # Example
+    /// ```
+    /// use rustcalculator::engine::functions::Function;
+    ///
+    /// assert_eq!(Function::from_name("sqrt"), Some(Function::Sqrt));
+    /// assert_eq!(Function::from_name("nope"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Function> {
+        let function = match name {
+            "sqrt" => Self::Sqrt,
+            "abs" => Self::Abs,
+            "sin" => Self::Sin,
+            "cos" => Self::Cos,
+            "tan" => Self::Tan,
+            "asin" => Self::Asin,
+            "acos" => Self::Acos,
+            "atan" => Self::Atan,
+            "atan2" => Self::Atan2,
+            "ln" => Self::Ln,
+            "log10" => Self::Log10,
+            "log" => Self::Log,
+            "exp" => Self::Exp,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "round" => Self::Round,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "nCr" => Self::Ncr,
+            "nPr" => Self::Npr,
+            "gamma" => Self::Gamma,
+            "arg" => Self::Arg,
+            "conj" => Self::Conj,
+            "re" => Self::Re,
+            "im" => Self::Im,
+            "if" => Self::If,
+            _ => return None,
+        };
+
+        Some(function)
+    }
+
+    /// Returns `true` when the function can be called with `count`
+    /// arguments.
+    pub fn accepts(&self, count: usize) -> bool {
+        match self {
+            Self::Atan2 | Self::Ncr | Self::Npr => count == 2,
+            Self::Log => count == 1 || count == 2,
+            Self::If => count == 3,
+            Self::Min | Self::Max => count >= 1,
+            _ => count == 1,
+        }
+    }
+}
+
+/// Computes a built-in function for any backend.
+///
+/// `abs`, `min` and `max` only compare and negate, `re`, `im` and `conj`
+/// of a real number are the number or zero, and `nCr` and `nPr` of
+/// integers are computed with big integers, so they stay exact. Every other
+/// function goes through `f64`, which is what `Number::call` does unless a
+/// backend knows better. The argument count must already have been
+/// checked with `Function::accepts`.
+pub fn approximate<N: Number>(function: Function, args: &[N], context: &N::Context) -> Result<N, ArithmeticError> {
+    match function {
+        Function::Abs => {
+            let zero = N::from_f64(0.0, context).ok_or(ArithmeticError::Overflow)?;
+            if args[0].compare(&zero) == Some(Ordering::Less) {
+                args[0].neg(context)
+            } else {
+                Ok(args[0].clone())
+            }
+        },
+        Function::Re | Function::Conj => Ok(args[0].clone()),
+        Function::Im => N::from_f64(0.0, context).ok_or(ArithmeticError::Overflow),
+        Function::Min | Function::Max => {
+            let wanted = if function == Function::Min { Ordering::Less } else { Ordering::Greater };
+            let mut best = &args[0];
+            for arg in &args[1..] {
+                match arg.compare(best) {
+                    Some(ordering) if ordering == wanted => best = arg,
+                    Some(_) => {},
+                    None => return Err(ArithmeticError::Undefined),
+                }
+            }
+            Ok(best.clone())
+        },
+        Function::Npr => {
+            let (n, r) = (whole(&args[0])?, whole(&args[1])?);
+            if r > n {
+                return N::from_f64(0.0, context).ok_or(ArithmeticError::Overflow);
+            }
+            N::from_integer(&falling_product(n, r)?, context).ok_or(ArithmeticError::Overflow)
+        },
+        Function::Ncr => {
+            let (n, r) = (whole(&args[0])?, whole(&args[1])?);
+            if r > n {
+                return N::from_f64(0.0, context).ok_or(ArithmeticError::Overflow);
+            }
+            // Multiplying and dividing one factor at a time keeps every
+            // step a whole binomial coefficient.
+            let r = r.min(n - r);
+            if r > MAX_FACTORIAL {
+                return Err(ArithmeticError::Overflow);
+            }
+            let mut result = BigInt::one();
+            for step in 1..=r {
+                result = result * (n - r + step) / step;
+            }
+            N::from_integer(&result, context).ok_or(ArithmeticError::Overflow)
+        },
+        _ => {
+            let values = args
+                .iter()
+                .map(real)
+                .collect::<Result<Vec<f64>, ArithmeticError>>()?;
+            let result = call_f64(function, &values)?;
+
+            N::from_f64(result, context).ok_or(ArithmeticError::Overflow)
+        },
+    }
+}
+
+/// Computes a built-in function on `f64` values, checking its domain.
+pub fn call_f64(function: Function, args: &[f64]) -> Result<f64, ArithmeticError> {
+    let x = args[0];
+    let result = match function {
+        Function::Sqrt => x.sqrt(),
+        Function::Abs => x.abs(),
+        Function::Sin => x.sin(),
+        Function::Cos => x.cos(),
+        Function::Tan => x.tan(),
+        Function::Asin => x.asin(),
+        Function::Acos => x.acos(),
+        Function::Atan => x.atan(),
+        Function::Atan2 => x.atan2(args[1]),
+        Function::Ln => logarithm(x, std::f64::consts::E)?,
+        Function::Log10 => logarithm(x, 10.0)?,
+        Function::Log => logarithm(x, args.get(1).copied().unwrap_or(10.0))?,
+        Function::Exp => x.exp(),
+        Function::Floor => x.floor(),
+        Function::Ceil => x.ceil(),
+        Function::Round => x.round(),
+        Function::Min => args.iter().copied().fold(x, f64::min),
+        Function::Max => args.iter().copied().fold(x, f64::max),
+        Function::Ncr | Function::Npr => {
+            let values = [x, args[1]];
+            return approximate::<f64>(function, &values, &());
+        },
+        Function::Gamma => gamma(x)?,
+        Function::Arg => if x < 0.0 { std::f64::consts::PI } else { 0.0 },
+        Function::Re | Function::Conj => x,
+        Function::Im => 0.0,
+        Function::If => unreachable!("if is evaluated by the evaluator through jumps"),
+    };
+
+    if result.is_nan() {
+        Err(ArithmeticError::Undefined)
+    } else if result.is_infinite() {
+        Err(ArithmeticError::Overflow)
+    } else {
+        Ok(result)
+    }
+}
+
+fn logarithm(x: f64, base: f64) -> Result<f64, ArithmeticError> {
+    if x <= 0.0 || base <= 0.0 || base == 1.0 {
+        return Err(ArithmeticError::Undefined);
+    }
+
+    // The dedicated functions are exact on powers of their base.
+    let result = if base == 10.0 {
+        x.log10()
+    } else if base == 2.0 {
+        x.log2()
+    } else if base == std::f64::consts::E {
+        x.ln()
+    } else {
+        x.ln() / base.ln()
+    };
+
+    Ok(result)
+}
+
+/// Computes `x!` for any backend.
+///
+/// The factorial of a whole number is a product of whole numbers, so exact
+/// backends keep it exact; anything else goes through `gamma(x + 1)`.
+///
+/// # Returns
+/// Returns `Undefined` for negative whole numbers and values that are not
+/// real, and `Overflow` when the result is too large for the backend or `x`
+/// is above `MAX_FACTORIAL`.
+pub fn factorial<N: Number>(x: &N, context: &N::Context) -> Result<N, ArithmeticError> {
+    let value = real(x)?;
+    if value.fract() != 0.0 {
+        let result = gamma(value + 1.0)?;
+        return N::from_f64(result, context).ok_or(ArithmeticError::Overflow);
+    }
+
+    let n = whole(x)?;
+    N::from_integer(&falling_product(n, n)?, context).ok_or(ArithmeticError::Overflow)
+}
+
+/// Converts an argument to `f64`.
+///
+/// # Returns
+/// Returns `Undefined` for values off the real line, such as complex ones,
+/// which cannot even be compared with themselves, and `Overflow` for those
+/// too large for an `f64`.
+fn real<N: Number>(x: &N) -> Result<f64, ArithmeticError> {
+    match x.to_f64() {
+        Some(value) => Ok(value),
+        None if x.compare(x).is_none() => Err(ArithmeticError::Undefined),
+        None => Err(ArithmeticError::Overflow),
+    }
+}
+
+/// Reads a whole, non-negative argument such as the `n` of `n!`.
+///
+/// # Returns
+/// Returns `Overflow` for values too large for a `u64`.
+fn whole<N: Number>(x: &N) -> Result<u64, ArithmeticError> {
+    let value = real(x)?;
+    if value < 0.0 || value.fract() != 0.0 {
+        return Err(ArithmeticError::Undefined);
+    }
+
+    value.to_u64().ok_or(ArithmeticError::Overflow)
+}
+
+/// Computes `n * (n - 1) * ... * (n - count + 1)` with big integers, which
+/// backends such as `Rational` are much slower to multiply one at a time.
+///
+/// # Returns
+/// Returns `Overflow` when `count` is above `MAX_FACTORIAL`.
+fn falling_product(n: u64, count: u64) -> Result<BigInt, ArithmeticError> {
+    if count > MAX_FACTORIAL {
+        return Err(ArithmeticError::Overflow);
+    }
+
+    Ok(((n - count + 1)..=n).fold(BigInt::one(), |product, factor| product * factor))
+}
+
+/// Computes the gamma function with the Lanczos approximation.
+fn gamma(x: f64) -> Result<f64, ArithmeticError> {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x.fract() == 0.0 {
+        if x <= 0.0 {
+            return Err(ArithmeticError::Undefined);
+        }
+        if x > 171.0 {
+            return Err(ArithmeticError::Overflow);
+        }
+        // Whole numbers are plain factorials, which the product gets exactly
+        // right where the approximation would be a few ulps off.
+        return Ok((1..x as u64).fold(1.0, |product, factor| product * factor as f64));
+    }
+    if x < 0.5 {
+        // Reflection formula: gamma(x) * gamma(1 - x) = pi / sin(pi * x).
+        let pi = std::f64::consts::PI;
+        return Ok(pi / ((pi * x).sin() * gamma(1.0 - x)?));
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+
+    Ok((2.0 * std::f64::consts::PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * sum)
+}
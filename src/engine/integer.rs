@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_integer::Integer as _;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+use crate::engine::functions::{self, Function};
+use crate::engine::number::{ArithmeticError, Number, Remainder, MAX_EXACT_POW_BITS};
+
+/// The largest magnitude up to which an `f64` holds every integer, `2^53`.
+const MAX_EXACT_FLOAT: f64 = 9_007_199_254_740_992.0;
+
+/// An arbitrary-precision integer backend.
+///
+/// Integer results are exact at any size. As soon as a division leaves a
+/// remainder, or a literal has a fractional part, the value is promoted to
+/// an `f64` and the rest of the computation carries on in floating point.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::resolve_as;
+/// use rustcalculator::engine::integer::Integer;
+///
+/// assert_eq!(resolve_as::<Integer>("99999999999 * 99999999999"), Ok("9999999999800000000001".to_owned()));
+/// assert_eq!(resolve_as::<Integer>("7 / 2"), Ok("3.5".to_owned()));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Integer {
+    Exact(BigInt),
+    Float(f64),
+}
+
+impl Integer {
+    /// Returns the value as an `f64`, failing when it is too large.
+    fn as_f64(&self) -> Result<f64, ArithmeticError> {
+        let value = match self {
+            Self::Exact(value) => value.to_f64().unwrap_or(f64::INFINITY),
+            Self::Float(value) => *value,
+        };
+
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(ArithmeticError::Overflow)
+        }
+    }
+
+    fn apply(
+        &self,
+        rhs: &Self,
+        exact: fn(&BigInt, &BigInt) -> BigInt,
+        float: fn(&f64, &f64, &()) -> Result<f64, ArithmeticError>,
+    ) -> Result<Self, ArithmeticError> {
+        match (self, rhs) {
+            (Self::Exact(left), Self::Exact(right)) => Ok(Self::Exact(exact(left, right))),
+            _ => float(&self.as_f64()?, &rhs.as_f64()?, &()).map(Self::Float),
+        }
+    }
+}
+
+impl Number for Integer {
+    type Context = ();
+
+    fn from_literal(literal: &str, context: &()) -> Option<Self> {
+        let (whole, fraction) = literal.split_once('.').unwrap_or((literal, ""));
+
+        if whole.is_empty() && fraction.is_empty() {
+            None
+        } else if fraction.chars().all(|c| c == '0') {
+            format!("0{}", whole).parse().ok().map(Self::Exact)
+        } else {
+            f64::from_literal(literal, context).map(Self::Float)
+        }
+    }
+
+    fn add(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+        self.apply(rhs, |left, right| left + right, <f64 as Number>::add)
+    }
+
+    fn sub(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+        self.apply(rhs, |left, right| left - right, <f64 as Number>::sub)
+    }
+
+    fn mul(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+        self.apply(rhs, |left, right| left * right, <f64 as Number>::mul)
+    }
+
+    fn div(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+        if let (Self::Exact(left), Self::Exact(right)) = (self, rhs) {
+            if right.is_zero() {
+                return Err(ArithmeticError::DivisionByZero);
+            }
+
+            let (quotient, remainder) = left.div_rem(right);
+            if remainder.is_zero() {
+                return Ok(Self::Exact(quotient));
+            }
+        }
+
+        <f64 as Number>::div(&self.as_f64()?, &rhs.as_f64()?, &()).map(Self::Float)
+    }
+
+    fn rem(&self, rhs: &Self, mode: Remainder, _context: &()) -> Result<Self, ArithmeticError> {
+        if let (Self::Exact(left), Self::Exact(right)) = (self, rhs) {
+            if right.is_zero() {
+                return Err(ArithmeticError::DivisionByZero);
+            }
+
+            return Ok(Self::Exact(match mode {
+                Remainder::Truncated => left % right,
+                Remainder::Floored => left.mod_floor(right),
+            }));
+        }
+
+        <f64 as Number>::rem(&self.as_f64()?, &rhs.as_f64()?, mode, &()).map(Self::Float)
+    }
+
+    fn floor_div(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+        if let (Self::Exact(left), Self::Exact(right)) = (self, rhs) {
+            if right.is_zero() {
+                return Err(ArithmeticError::DivisionByZero);
+            }
+            return Ok(Self::Exact(left.div_floor(right)));
+        }
+
+        <f64 as Number>::floor_div(&self.as_f64()?, &rhs.as_f64()?, &()).map(Self::Float)
+    }
+
+    /// A whole base raised to a non-negative whole exponent stays exact,
+    /// anything else is promoted.
+    fn pow(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+        if let (Self::Exact(base), Self::Exact(exponent)) = (self, rhs) {
+            if !exponent.is_negative() {
+                // Powers of 0, 1 and -1 stay small however large the
+                // exponent is.
+                if base.is_zero() || base.abs().is_one() {
+                    let one = exponent.is_zero() || (base.is_negative() && exponent.is_even());
+                    return Ok(Self::Exact(if one { BigInt::one() } else { base.clone() }));
+                }
+                let exponent = exponent.to_u32().ok_or(ArithmeticError::Overflow)?;
+                if base.bits() > 1 && base.bits().saturating_mul(exponent as u64) > MAX_EXACT_POW_BITS {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                return Ok(Self::Exact(base.pow(exponent)));
+            }
+        }
+
+        <f64 as Number>::pow(&self.as_f64()?, &rhs.as_f64()?, &()).map(Self::Float)
+    }
+
+    fn neg(&self, _context: &()) -> Result<Self, ArithmeticError> {
+        match self {
+            Self::Exact(value) => Ok(Self::Exact(-value)),
+            Self::Float(value) => Ok(Self::Float(-value)),
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Exact(left), Self::Exact(right)) => Some(left.cmp(right)),
+            _ => self.as_f64().ok()?.partial_cmp(&other.as_f64().ok()?),
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.as_f64().ok()
+    }
+
+    fn is_exact(&self) -> bool {
+        matches!(self, Self::Exact(_))
+    }
+
+    fn to_integer(&self) -> Option<BigInt> {
+        match self {
+            Self::Exact(value) => Some(value.clone()),
+            Self::Float(value) => Some(*value).filter(|value| value.fract() == 0.0).and_then(BigInt::from_f64),
+        }
+    }
+
+    fn from_integer(value: &BigInt, _context: &()) -> Option<Self> {
+        Some(Self::Exact(value.clone()))
+    }
+
+    /// Whole values come back exact, so `sqrt(16)` is the integer `4`, as
+    /// long as an `f64` holds every integer up to them. Larger ones, such
+    /// as `exp(100)`, stay approximate.
+    fn from_f64(value: f64, _context: &()) -> Option<Self> {
+        if !value.is_finite() {
+            None
+        } else if value.fract() == 0.0 && value.abs() <= MAX_EXACT_FLOAT {
+            BigInt::from_f64(value).map(Self::Exact)
+        } else {
+            Some(Self::Float(value))
+        }
+    }
+
+    /// Rounding an exact value leaves it unchanged.
+    fn call(function: Function, args: &[Self], context: &()) -> Result<Self, ArithmeticError> {
+        match (function, &args[0]) {
+            (Function::Floor | Function::Ceil | Function::Round, Self::Exact(_)) => Ok(args[0].clone()),
+            _ => functions::approximate(function, args, context),
+        }
+    }
+
+    fn format(&self, context: &()) -> String {
+        match self {
+            Self::Exact(value) => value.to_string(),
+            Self::Float(value) => value.format(context),
+        }
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format(&()))
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_traits::FromPrimitive;
+
+use crate::engine::functions::{self, Function};
+
+/// Errors a `Number` backend can raise while computing a result. The
+/// evaluator turns them into an `Error` pointing at the offending operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticError {
+    DivisionByZero,
+    Overflow,
+    /// The operation has no result for its operands, as in `(-8) ^ 0.5`.
+    Undefined,
+    /// An operation that only works on integers was given a fraction, as in
+    /// `1.5 & 1`.
+    NotAnInteger,
+}
+
+/// How the remainder of a division picks its sign.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Remainder {
+    /// The result has the sign of the dividend, as in C: `-7 % 3` is `-1`.
+    #[default]
+    Truncated,
+    /// The result has the sign of the divisor, as in Python: `-7 % 3` is `2`.
+    Floored,
+}
+
+/// The numeric type the engine computes with.
+///
+/// The tokenizer, the shunting-yard converter and the evaluator are generic
+/// over this trait, so a new backend only has to describe how to read a
+/// literal, how to do arithmetic and how to print itself.
+pub trait Number: Clone + fmt::Debug + PartialEq + Sized {
+    /// Settings every operation of the backend is carried out with, such as
+    /// the precision of a decimal. Backends without settings use `()`.
+    type Context: Clone + fmt::Debug + Default;
+
+    /// Reads a decimal literal such as `12`, `0.5` or `3.` as written in the
+    /// expression.
+    ///
+    /// # Returns
+    /// Returns `None` when the literal is malformed, as in `1.2.3`, or cannot
+    /// be represented by the backend.
+    fn from_literal(literal: &str, context: &Self::Context) -> Option<Self>;
+
+    /// Reads a literal in scientific notation, `mantissa` being the decimal
+    /// literal before the `e` and `exponent` the power of ten after it, as
+    /// in `1.5e-3`. SI suffixes such as the `k` of `4.7k` arrive here too.
+    ///
+    /// By default the mantissa is multiplied or divided by a power of ten,
+    /// which exact backends carry out exactly.
+    ///
+    /// # Returns
+    /// Returns `None` when the literal is malformed or the value cannot be
+    /// represented by the backend, which is reported as an overflow when
+    /// the mantissa alone is valid.
+    fn from_scientific(mantissa: &str, exponent: i32, context: &Self::Context) -> Option<Self> {
+        let mantissa = Self::from_literal(mantissa, context)?;
+        let ten = Self::from_literal("10", context)?;
+        let magnitude = Self::from_literal(&exponent.unsigned_abs().to_string(), context)?;
+        let scale = ten.pow(&magnitude, context).ok()?;
+
+        if exponent < 0 {
+            mantissa.div(&scale, context).ok()
+        } else {
+            mantissa.mul(&scale, context).ok()
+        }
+    }
+
+    fn add(&self, rhs: &Self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+    fn sub(&self, rhs: &Self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+    fn mul(&self, rhs: &Self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+    fn div(&self, rhs: &Self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+    fn neg(&self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+    fn pow(&self, rhs: &Self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+
+    /// Returns what is left after dividing by `rhs` a whole number of times.
+    fn rem(&self, rhs: &Self, mode: Remainder, context: &Self::Context) -> Result<Self, ArithmeticError>;
+
+    /// Divides by `rhs` and rounds the quotient toward negative infinity.
+    fn floor_div(&self, rhs: &Self, context: &Self::Context) -> Result<Self, ArithmeticError>;
+
+    /// Compares two numbers, returning `None` when they are not comparable.
+    fn compare(&self, other: &Self) -> Option<Ordering>;
+
+    /// Converts the number to an `f64`, used by functions the backend has no
+    /// exact implementation for.
+    ///
+    /// # Returns
+    /// Returns `None` when the number is out of the `f64` range.
+    fn to_f64(&self) -> Option<f64>;
+
+    /// Converts an `f64` back into the backend.
+    ///
+    /// # Returns
+    /// Returns `None` when the value cannot be represented.
+    fn from_f64(value: f64, context: &Self::Context) -> Option<Self>;
+
+    /// Converts a whole number to a `BigInt`, used by the bitwise operators.
+    /// By default this goes through `to_f64`; exact backends override it.
+    ///
+    /// # Returns
+    /// Returns `None` when the number has a fractional part.
+    fn to_integer(&self) -> Option<BigInt> {
+        self.to_f64().filter(|value| value.fract() == 0.0).and_then(BigInt::from_f64)
+    }
+
+    /// Converts a `BigInt` back into the backend.
+    ///
+    /// # Returns
+    /// Returns `None` when the value cannot be represented.
+    fn from_integer(value: &BigInt, context: &Self::Context) -> Option<Self> {
+        Self::from_literal(&value.to_string(), context)
+    }
+
+    /// Returns `true` when the value is held exactly, as integers and
+    /// fractions are, so that writing out all of its digits is meaningful.
+    /// `Notation::Auto` switches only other values to scientific notation.
+    fn is_exact(&self) -> bool {
+        false
+    }
+
+    /// Returns the imaginary unit, which the name `i` stands for, if the
+    /// backend has complex numbers.
+    fn imaginary_unit(_context: &Self::Context) -> Option<Self> {
+        None
+    }
+
+    /// Calls a built-in function with arguments whose count has already been
+    /// checked. By default everything but `abs`, `min` and `max` is computed
+    /// through `f64`; backends override this to keep more functions exact.
+    fn call(function: Function, args: &[Self], context: &Self::Context) -> Result<Self, ArithmeticError> {
+        functions::approximate(function, args, context)
+    }
+
+    /// Formats the number for display.
+    fn format(&self, context: &Self::Context) -> String;
+}
+
+/// The largest result, in bits, exact backends agree to compute for a
+/// power before reporting an overflow.
+pub(crate) const MAX_EXACT_POW_BITS: u64 = 1 << 20;
+
+macro_rules! impl_float_number {
+    ($float:ty) => {
+        impl Number for $float {
+            type Context = ();
+
+            fn from_literal(literal: &str, _context: &()) -> Option<Self> {
+                literal.parse().ok().filter(|value: &Self| value.is_finite())
+            }
+
+            fn from_scientific(mantissa: &str, exponent: i32, context: &()) -> Option<Self> {
+                Self::from_literal(&format!("{}e{}", mantissa, exponent), context)
+            }
+
+            fn add(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+                finite(self + rhs)
+            }
+
+            fn sub(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+                finite(self - rhs)
+            }
+
+            fn mul(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+                finite(self * rhs)
+            }
+
+            fn div(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+                if *rhs == 0.0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+                finite(self / rhs)
+            }
+
+            fn neg(&self, _context: &()) -> Result<Self, ArithmeticError> {
+                Ok(-self)
+            }
+
+            fn rem(&self, rhs: &Self, mode: Remainder, _context: &()) -> Result<Self, ArithmeticError> {
+                if *rhs == 0.0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let remainder = self % rhs;
+                if mode == Remainder::Floored && remainder != 0.0 && (remainder < 0.0) != (*rhs < 0.0) {
+                    return finite(remainder + rhs);
+                }
+                finite(remainder)
+            }
+
+            fn floor_div(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+                if *rhs == 0.0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+                finite((self / rhs).floor())
+            }
+
+            fn pow(&self, rhs: &Self, _context: &()) -> Result<Self, ArithmeticError> {
+                if *self == 0.0 && *rhs < 0.0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let result = self.powf(*rhs);
+                if result.is_nan() {
+                    return Err(ArithmeticError::Undefined);
+                }
+                finite(result)
+            }
+
+            fn compare(&self, other: &Self) -> Option<Ordering> {
+                self.partial_cmp(other)
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(*self as f64)
+            }
+
+            fn from_f64(value: f64, _context: &()) -> Option<Self> {
+                Some(value as $float).filter(|value| value.is_finite())
+            }
+
+            fn format(&self, _context: &()) -> String {
+                // Avoid printing `-0`.
+                let value = if *self == 0.0 { 0.0 } else { *self };
+                if value.fract() == 0.0 {
+                    format!("{:.0}", value)
+                } else {
+                    format!("{:?}", value)
+                }
+            }
+        }
+    };
+}
+
+impl_float_number!(f32);
+impl_float_number!(f64);
+
+fn finite<F: Into<f64> + Copy>(value: F) -> Result<F, ArithmeticError> {
+    if value.into().is_finite() {
+        Ok(value)
+    } else {
+        Err(ArithmeticError::Overflow)
+    }
+}
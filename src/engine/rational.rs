@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::engine::functions::{self, Function};
+use crate::engine::number::{ArithmeticError, Number, Remainder, MAX_EXACT_POW_BITS};
+
+/// How a `Rational` result is written out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FractionStyle {
+    /// A reduced fraction, such as `3/2`.
+    #[default]
+    Fraction,
+    /// A whole part followed by a proper fraction, such as `1 1/2`.
+    Mixed,
+    /// A decimal rounded to `RationalContext::decimal_places`, such as `1.5`.
+    Decimal,
+}
+
+/// The settings a `Rational` result is displayed with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RationalContext {
+    pub style: FractionStyle,
+    /// The number of digits kept after the decimal point when `style` is
+    /// `FractionStyle::Decimal`.
+    pub decimal_places: u32,
+}
+
+impl Default for RationalContext {
+    fn default() -> Self {
+        RationalContext { style: FractionStyle::default(), decimal_places: 10 }
+    }
+}
+
+/// An exact fraction backend with arbitrarily large numerator and
+/// denominator.
+///
+/// Values are always kept reduced, so `1/3 + 1/6` is exactly `1/2`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve_as, resolve_with, Settings};
+/// use rustcalculator::engine::rational::{FractionStyle, Rational, RationalContext};
+///
+/// assert_eq!(resolve_as::<Rational>("1/3 + 1/6"), Ok("1/2".to_owned()));
+///
+/// let context = RationalContext { style: FractionStyle::Mixed, ..Default::default() };
+/// assert_eq!(resolve_with::<Rational>("3/2", &Settings::new(context)), Ok("1 1/2".to_owned()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rational(pub BigRational);
+
+impl Rational {
+    /// Writes the value as a reduced fraction.
+    pub fn to_fraction(&self) -> String {
+        if self.0.is_integer() {
+            self.0.numer().to_string()
+        } else {
+            format!("{}/{}", self.0.numer(), self.0.denom())
+        }
+    }
+
+    /// Writes the value as a whole part followed by a proper fraction.
+    pub fn to_mixed(&self) -> String {
+        let (whole, remainder) = self.0.numer().div_rem(self.0.denom());
+
+        if remainder.is_zero() {
+            whole.to_string()
+        } else if whole.is_zero() {
+            self.to_fraction()
+        } else {
+            format!("{} {}/{}", whole, remainder.abs(), self.0.denom())
+        }
+    }
+
+    /// Writes the value as a decimal, rounding half away from zero after
+    /// `places` digits and dropping trailing zeros.
+    pub fn to_decimal(&self, places: u32) -> String {
+        let scale = BigInt::from(10u32).pow(places);
+        let numer = self.0.numer().abs() * &scale * 2u32 + self.0.denom();
+        let scaled = numer / (self.0.denom() * 2u32);
+        let (whole, fraction) = scaled.div_rem(&scale);
+
+        let sign = if self.0.is_negative() && !scaled.is_zero() { "-" } else { "" };
+        let fraction = format!("{:0>width$}", fraction.to_string(), width = places as usize);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            format!("{}{}", sign, whole)
+        } else {
+            format!("{}{}.{}", sign, whole, fraction)
+        }
+    }
+}
+
+impl Number for Rational {
+    type Context = RationalContext;
+
+    fn from_literal(literal: &str, _context: &RationalContext) -> Option<Self> {
+        let (whole, fraction) = literal.split_once('.').unwrap_or((literal, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+
+        let digits: BigInt = format!("0{}{}", whole, fraction).parse().ok()?;
+        let scale = BigInt::from(10u32).pow(fraction.len() as u32);
+
+        Some(Rational(BigRational::new(digits, scale)))
+    }
+
+    fn add(&self, rhs: &Self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        Ok(Rational(&self.0 + &rhs.0))
+    }
+
+    fn sub(&self, rhs: &Self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        Ok(Rational(&self.0 - &rhs.0))
+    }
+
+    fn mul(&self, rhs: &Self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        Ok(Rational(&self.0 * &rhs.0))
+    }
+
+    fn div(&self, rhs: &Self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        if rhs.0.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Ok(Rational(&self.0 / &rhs.0))
+    }
+
+    fn neg(&self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        Ok(Rational(-&self.0))
+    }
+
+    fn rem(&self, rhs: &Self, mode: Remainder, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        if rhs.0.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let quotient = &self.0 / &rhs.0;
+        let quotient = match mode {
+            Remainder::Truncated => quotient.trunc(),
+            Remainder::Floored => quotient.floor(),
+        };
+        Ok(Rational(&self.0 - &rhs.0 * quotient))
+    }
+
+    fn floor_div(&self, rhs: &Self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        if rhs.0.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Ok(Rational((&self.0 / &rhs.0).floor()))
+    }
+
+    /// Whole exponents keep the result exact, anything else goes through
+    /// `f64`.
+    fn pow(&self, rhs: &Self, _context: &RationalContext) -> Result<Self, ArithmeticError> {
+        if self.0.is_zero() && rhs.0.is_negative() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        if !rhs.0.is_integer() {
+            let base = self.0.to_f64().ok_or(ArithmeticError::Overflow)?;
+            let exponent = rhs.0.to_f64().ok_or(ArithmeticError::Overflow)?;
+            let result = <f64 as Number>::pow(&base, &exponent, &())?;
+
+            return BigRational::from_float(result).map(Rational).ok_or(ArithmeticError::Overflow);
+        }
+
+        let bits = self.0.numer().bits().max(self.0.denom().bits());
+        let exponent = rhs.0.to_integer().to_i32().ok_or(ArithmeticError::Overflow)?;
+        if bits > 1 && bits.saturating_mul(exponent.unsigned_abs() as u64) > MAX_EXACT_POW_BITS {
+            return Err(ArithmeticError::Overflow);
+        }
+
+        Ok(Rational(self.0.pow(exponent)))
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64().filter(|value| value.is_finite())
+    }
+
+    fn from_f64(value: f64, _context: &RationalContext) -> Option<Self> {
+        BigRational::from_float(value).map(Rational)
+    }
+
+    fn is_exact(&self) -> bool {
+        true
+    }
+
+    fn to_integer(&self) -> Option<BigInt> {
+        self.0.is_integer().then(|| self.0.to_integer())
+    }
+
+    fn from_integer(value: &BigInt, _context: &RationalContext) -> Option<Self> {
+        Some(Rational(BigRational::from_integer(value.clone())))
+    }
+
+    /// Rounding functions stay exact.
+    fn call(function: Function, args: &[Self], context: &RationalContext) -> Result<Self, ArithmeticError> {
+        match function {
+            Function::Floor => Ok(Rational(args[0].0.floor())),
+            Function::Ceil => Ok(Rational(args[0].0.ceil())),
+            Function::Round => Ok(Rational(args[0].0.round())),
+            _ => functions::approximate(function, args, context),
+        }
+    }
+
+    fn format(&self, context: &RationalContext) -> String {
+        match context.style {
+            FractionStyle::Fraction => self.to_fraction(),
+            FractionStyle::Mixed => self.to_mixed(),
+            FractionStyle::Decimal => self.to_decimal(context.decimal_places),
+        }
+    }
+}
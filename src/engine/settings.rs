@@ -0,0 +1,83 @@
+use crate::engine::number::{Number, Remainder};
+
+/// How tightly a multiplication without `*`, as in `2x` or `2(3 + 4)`,
+/// binds. The choice matters for expressions like `1/2x`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImplicitMultiplication {
+    /// Like `*`, so `1/2x` reads as `(1/2) * x`.
+    #[default]
+    Ordinary,
+    /// Tighter than `*` and `/`, so `1/2x` reads as `1 / (2 * x)`.
+    Tight,
+}
+
+/// How results are written out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Like `Scientific` for values the backend only approximates, such as
+    /// `f64` results, and like `Plain` for exact ones, such as integers and
+    /// fractions, whose digits are all meaningful.
+    #[default]
+    Auto,
+    /// As the backend formats them, such as `1234500`.
+    Plain,
+    /// A mantissa between 1 and 10 and a power of ten, such as `1.2345e6`.
+    Scientific,
+    /// Like `Scientific` with the power of ten a multiple of three, such as
+    /// `1.2345e6` or `12.345e3`, matching the SI prefixes.
+    Engineering,
+}
+
+/// Everything an evaluation can be tuned with: the settings of the `Number`
+/// backend plus the options of the engine itself.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve_with, Remainder, Settings};
+///
+/// let settings = Settings::<f64> { remainder: Remainder::Floored, ..Default::default() };
+///
+/// assert_eq!(resolve_with("-7 % 3", &settings), Ok("2".to_owned()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Settings<N: Number = f64> {
+    /// The settings of the backend, such as the precision of a decimal.
+    pub context: N::Context,
+    /// How `%` picks the sign of its result.
+    pub remainder: Remainder,
+    /// How tightly a multiplication without `*` binds.
+    pub implicit_multiplication: ImplicitMultiplication,
+    /// Whether a number literal can end in an SI prefix, as in `4.7k` or
+    /// `220n`. Off by default since it takes names like `m` or `n` away
+    /// from implicit multiplication: `2n` is `2e-9` rather than `2 * n`.
+    pub si_suffixes: bool,
+    /// How results whose magnitude reaches `notation_threshold` are
+    /// written out. Other results are always plain. By default approximate
+    /// results switch to scientific notation, so `1e300` is not written
+    /// out with all of its digits.
+    pub notation: Notation,
+    /// The power of ten from which `notation` applies: results of at least
+    /// `10^threshold`, or below `10^-threshold` but not zero, use it.
+    pub notation_threshold: i32,
+}
+
+impl<N: Number> Settings<N> {
+    /// Creates settings using the given backend `context` and the default
+    /// engine options.
+    pub fn new(context: N::Context) -> Self {
+        Settings {
+            context,
+            remainder: Remainder::default(),
+            implicit_multiplication: ImplicitMultiplication::default(),
+            si_suffixes: false,
+            notation: Notation::default(),
+            notation_threshold: 12,
+        }
+    }
+}
+
+impl<N: Number> Default for Settings<N> {
+    fn default() -> Self {
+        Settings::new(N::Context::default())
+    }
+}
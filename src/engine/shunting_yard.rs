@@ -0,0 +1,149 @@
+use crate::engine::span::Spanned;
+use crate::engine::token::{Associativity, Token};
+
+/// Reorders infix tokens into reverse polish notation using the
+/// shunting-yard algorithm.
+///
+/// Operators are moved behind their operands according to
+/// `Operator::precedence` and `Operator::associativity`, prefix operators
+/// are moved behind their single operand, postfix operators stay right
+/// behind theirs, function names become a `Token::Call` behind their
+/// arguments, except for `if`, whose branches are separated by a
+/// `Token::JumpUnless` and a `Token::Jump` so that only one of them is
+/// evaluated, and brackets and commas are consumed, so the output can be
+/// fed straight into `evaluate`. Tokens keep their spans.
+///
+/// # Returns
+/// Returns the tokens in postfix order.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{to_rpn, tokenize, Operator, Token};
+///
+/// let queue: Vec<Token> = to_rpn(tokenize::<f64>("1 + 2 * 3").unwrap())
+///     .into_iter()
+///     .map(|token| token.value)
+///     .collect();
+///
+/// assert_eq!(queue, vec![
+///     Token::Number(1.0), Token::Number(2.0), Token::Number(3.0),
+///     Token::Operator(Operator::Mul), Token::Operator(Operator::Add)
+/// ]);
+/// ```
+pub fn to_rpn<N>(mut tokens: Vec<Spanned<Token<N>>>) -> Vec<Spanned<Token<N>>> {
+    tokens.reverse();
+
+    let mut queue: Vec<Spanned<Token<N>>> = Vec::new();
+    let mut stack: Vec<Spanned<Token<N>>> = Vec::new();
+    // The number of arguments read so far for every open function call.
+    let mut arguments: Vec<usize> = Vec::new();
+    // The jumps emitted so far for every open function call, only ever
+    // filled for `if`.
+    let mut jumps: Vec<Vec<usize>> = Vec::new();
+    let mut previous_was_open = false;
+
+    while let Some(token) = tokens.pop() {
+        let is_open = matches!(token.value, Token::Bracket('('));
+        match token.value {
+            Token::Number(_) | Token::Identifier(_) => {
+                queue.push(token);
+            },
+            Token::Operator(current_operator) if current_operator.is_unary() => {
+                stack.push(token);
+            },
+            Token::Operator(current_operator) if current_operator.is_postfix() => {
+                queue.push(token);
+            },
+            Token::Operator(current_operator) => {
+                while let Some(Token::Operator(last_stack_operator)) = stack.last().map(|top| &top.value) {
+                    let binds_tighter = last_stack_operator.precedence() > current_operator.precedence()
+                        || (last_stack_operator.precedence() == current_operator.precedence()
+                            && current_operator.associativity() == Associativity::Left);
+
+                    if binds_tighter {
+                        queue.push(stack.pop().unwrap());
+                    } else {
+                        break;
+                    }
+                }
+                stack.push(token);
+            },
+            Token::Function(_) => {
+                arguments.push(1);
+                jumps.push(Vec::new());
+                stack.push(token);
+            },
+            Token::Comma => {
+                pop_until_open_bracket(&mut stack, &mut queue);
+                if let (Some(count), Some(jumps)) = (arguments.last_mut(), jumps.last_mut()) {
+                    if is_if(&stack) && *count <= 2 {
+                        // The first `,` skips to the third argument when the
+                        // condition is false, the second one skips past it.
+                        if let Some(&branch) = jumps.last() {
+                            queue[branch].value = Token::JumpUnless(queue.len() + 1);
+                        }
+                        let jump = if *count == 1 { Token::JumpUnless(0) } else { Token::Jump(0) };
+                        jumps.push(queue.len());
+                        queue.push(Spanned::new(jump, token.span));
+                    }
+                    *count += 1;
+                }
+            },
+            Token::Bracket('(') => {
+                stack.push(token);
+            },
+            Token::Bracket(')') => {
+                pop_until_open_bracket(&mut stack, &mut queue);
+                stack.pop();
+
+                if let Some(Token::Function(_)) = stack.last().map(|top| &top.value) {
+                    let function = stack.pop().unwrap();
+                    let count = arguments.pop().unwrap_or(0);
+                    let count = if previous_was_open { 0 } else { count };
+                    let jumps = jumps.pop().unwrap_or_default();
+                    let name = match function.value {
+                        Token::Function(name) => name,
+                        _ => unreachable!(),
+                    };
+
+                    if jumps.len() == 2 && count == 3 {
+                        queue[jumps[1]].value = Token::Jump(queue.len());
+                    } else {
+                        // A call with the wrong number of arguments is left
+                        // for the evaluator to report, its jumps doing nothing.
+                        for jump in jumps {
+                            queue[jump].value = Token::Jump(jump + 1);
+                        }
+                        queue.push(Spanned::new(Token::Call(name, count), function.span.to(token.span)));
+                    }
+                }
+            },
+            _ => {}
+        }
+        previous_was_open = is_open;
+    }
+
+    while let Some(token) = stack.pop() {
+        queue.push(token);
+    }
+
+    queue
+}
+
+/// Returns `true` when the innermost open bracket holds the arguments of
+/// `if`, whose branches are only evaluated when they are chosen.
+fn is_if<N>(stack: &[Spanned<Token<N>>]) -> bool {
+    matches!(stack, [.., Spanned { value: Token::Function(name), .. }, Spanned { value: Token::Bracket('('), .. }] if name == "if")
+}
+
+/// Moves operators from the stack to the queue up to the innermost open
+/// bracket, which is left on the stack.
+fn pop_until_open_bracket<N>(stack: &mut Vec<Spanned<Token<N>>>, queue: &mut Vec<Spanned<Token<N>>>) {
+    while let Some(top) = stack.pop() {
+        if matches!(top.value, Token::Bracket('(')) {
+            stack.push(top);
+            break;
+        }
+        queue.push(top);
+    }
+}
@@ -0,0 +1,63 @@
+use std::ops::Range;
+
+/// A range of byte offsets into the expression a token or error came from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    /// Returns the smallest span covering both `self` and `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// Returns the span as a byte range, ready to slice the expression with.
+    ///
+    /// # Example
+    /// ```
+    /// use rustcalculator::engine::Span;
+    ///
+    /// let expression = "12 + 345";
+    ///
+    /// assert_eq!(&expression[Span::new(5, 8).range()], "345");
+    /// ```
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the span as a range of characters instead of bytes, which is
+    /// what a display needs to underline the right part of `source`.
+    ///
+    /// # Example
+    /// ```
+    /// use rustcalculator::engine::Span;
+    ///
+    /// // 'π' takes two bytes but a single character.
+    /// assert_eq!(Span::new(3, 4).char_range("π+x"), 2..3);
+    /// ```
+    pub fn char_range(&self, source: &str) -> Range<usize> {
+        let start = source[..self.start].chars().count();
+        let len = source[self.range()].chars().count();
+
+        start..start + len
+    }
+}
+
+/// A value tagged with the part of the expression it was read from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Spanned { value, span }
+    }
+}
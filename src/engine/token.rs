@@ -0,0 +1,224 @@
+use std::fmt;
+
+/// The side an operator groups from when it is chained with operators of the
+/// same precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Associativity {
+    /// `1 - 2 - 3` reads as `(1 - 2) - 3`.
+    Left,
+    /// `2 ^ 3 ^ 2` reads as `2 ^ (3 ^ 2)`.
+    Right,
+}
+
+/// The levels operators bind at, from the loosest to the tightest. Those
+/// below `Additive` follow C, so `1 + 2 << 3 & 7` reads as
+/// `((1 + 2) << 3) & 7`, except for `not`, which like in Python applies to
+/// a whole comparison: `not x > 5` reads as `not (x > 5)`. Unit
+/// conversions apply to whole expressions: `1 ft + 1 m to cm` converts the
+/// sum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precedence {
+    /// `to` and `in`
+    Conversion,
+    /// `or`
+    Or,
+    /// `and`
+    And,
+    /// `not`
+    Not,
+    /// `|`
+    BitOr,
+    /// `xor`
+    BitXor,
+    /// `&`
+    BitAnd,
+    /// `==` and `!=`
+    Equality,
+    /// `<`, `<=`, `>` and `>=`
+    Relational,
+    /// `<<` and `>>`
+    Shift,
+    /// `+` and `-`
+    Additive,
+    /// `*`, `/`, `%` and `//`
+    Multiplicative,
+    /// A multiplication without `*` with `ImplicitMultiplication::Tight`,
+    /// or before a unit.
+    Implicit,
+    /// The prefix `-`, `+` and `~`.
+    Prefix,
+    /// `^`
+    Power,
+    /// The postfix `%` and `!`.
+    Postfix,
+}
+
+/// An operator understood by the engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    /// The remainder of a division, written `%`.
+    Rem,
+    /// Division rounded toward negative infinity, written `//`.
+    FloorDiv,
+    /// The prefix `-`, as in `-3` or `2 * -4`.
+    Neg,
+    /// The prefix `+`, as in `+3`.
+    Pos,
+    /// Exponentiation, written `^` or `**`.
+    Pow,
+    /// The postfix `%`, as in `10%`. On its own it divides by a hundred;
+    /// as the right-hand side of `+` or `-` it is a percentage of the
+    /// left-hand side, so `200 + 10%` is `220`.
+    Percent,
+    /// The postfix `!`, as in `5!`.
+    Factorial,
+    /// A multiplication written without `*`, as in `2x`, binding tighter
+    /// than `*` and `/`. Only produced with `ImplicitMultiplication::Tight`
+    /// and before a unit, as in `2 h`; otherwise the tokenizer uses `Mul`.
+    ImplicitMul,
+    /// Bitwise and of two integers, written `&`.
+    BitAnd,
+    /// Bitwise or of two integers, written `|`.
+    BitOr,
+    /// Bitwise exclusive or of two integers, written `xor`.
+    BitXor,
+    /// Bitwise complement of an integer, the prefix `~`.
+    BitNot,
+    /// Shift of an integer toward its high bits, written `<<`.
+    Shl,
+    /// Arithmetic shift of an integer toward its low bits, written `>>`.
+    Shr,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    /// Equality of two numbers or two booleans, written `==`.
+    Equal,
+    /// Inequality of two numbers or two booleans, written `!=`.
+    NotEqual,
+    /// Both of two booleans, written `and`.
+    And,
+    /// Either of two booleans, written `or`.
+    Or,
+    /// The negation of a boolean, the prefix `not`.
+    Not,
+    /// The conversion of a quantity to another unit, written `to` or `in`,
+    /// as in `60 mph to km/h`.
+    To,
+}
+
+impl Operator {
+    /// Returns the level the operator binds at.
+    pub fn precedence(&self) -> Precedence {
+        match self {
+            Self::To => Precedence::Conversion,
+            Self::Or => Precedence::Or,
+            Self::And => Precedence::And,
+            Self::Not => Precedence::Not,
+            Self::BitOr => Precedence::BitOr,
+            Self::BitXor => Precedence::BitXor,
+            Self::BitAnd => Precedence::BitAnd,
+            Self::Equal | Self::NotEqual => Precedence::Equality,
+            Self::Less | Self::LessEqual | Self::Greater | Self::GreaterEqual => Precedence::Relational,
+            Self::Shl | Self::Shr => Precedence::Shift,
+            Self::Add | Self::Sub => Precedence::Additive,
+            Self::Mul | Self::Div | Self::Rem | Self::FloorDiv => Precedence::Multiplicative,
+            Self::ImplicitMul => Precedence::Implicit,
+            Self::Neg | Self::Pos | Self::BitNot => Precedence::Prefix,
+            Self::Pow => Precedence::Power,
+            Self::Percent | Self::Factorial => Precedence::Postfix,
+        }
+    }
+
+    /// Returns the precedence of the operator as a number. Operators with a
+    /// higher value bind tighter than operators with a lower one.
+    pub fn value(&self) -> u16 {
+        self.precedence() as u16
+    }
+
+    /// Returns how the operator groups with operators of the same precedence.
+    pub fn associativity(&self) -> Associativity {
+        match self {
+            Self::Pow | Self::Neg | Self::Pos | Self::BitNot | Self::Not => Associativity::Right,
+            _ => Associativity::Left,
+        }
+    }
+
+    /// Returns `true` for prefix operators, which take a single operand on
+    /// their right.
+    pub fn is_unary(&self) -> bool {
+        matches!(self, Self::Neg | Self::Pos | Self::BitNot | Self::Not)
+    }
+
+    /// Returns `true` for postfix operators, which take a single operand on
+    /// their left.
+    pub fn is_postfix(&self) -> bool {
+        matches!(self, Self::Percent | Self::Factorial)
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Self::Add | Self::Pos => "+",
+            Self::Sub | Self::Neg => "-",
+            Self::Mul | Self::ImplicitMul => "*",
+            Self::Div => "/",
+            Self::Rem | Self::Percent => "%",
+            Self::FloorDiv => "//",
+            Self::Pow => "^",
+            Self::Factorial => "!",
+            Self::BitAnd => "&",
+            Self::BitOr => "|",
+            Self::BitXor => "xor",
+            Self::BitNot => "~",
+            Self::Shl => "<<",
+            Self::Shr => ">>",
+            Self::Less => "<",
+            Self::LessEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterEqual => ">=",
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::And => "and",
+            Self::Or => "or",
+            Self::Not => "not",
+            Self::To => "to",
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+/// A single lexical element of an expression, holding numbers of the
+/// backend `N`.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub enum Token<N = f64> {
+    Number(N),
+    Operator(Operator),
+    Bracket(char),
+    /// A name standing for a value, such as `pi`.
+    Identifier(String),
+    /// The `=` of an assignment, as in `rate = 0.07`.
+    Assign,
+    /// The name of a function, always followed by a `(` bracket.
+    Function(String),
+    /// The `,` between the arguments of a function.
+    Comma,
+    /// A call to the named function with the given number of arguments.
+    /// Only found in reverse polish notation, where it replaces the
+    /// `Function` token and its brackets.
+    Call(String, usize),
+    /// Takes a boolean and, when it is false, carries on at the given index
+    /// of the reverse polish tokens. Only found in reverse polish notation,
+    /// where it replaces the first `,` of an `if`.
+    JumpUnless(usize),
+    /// Carries on at the given index of the reverse polish tokens. Only
+    /// found in reverse polish notation, where it replaces the second `,`
+    /// of an `if`.
+    Jump(usize),
+}
@@ -0,0 +1,486 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use num_bigint::BigUint;
+
+use crate::engine::constants::Constant;
+use crate::engine::error::Error;
+use crate::engine::number::Number;
+use crate::engine::settings::{ImplicitMultiplication, Settings};
+use crate::engine::span::{Span, Spanned};
+use crate::engine::token::{Operator, Token};
+use crate::engine::units::Unit;
+
+/// Splits an infix expression into a list of tokens.
+///
+/// Whitespace is ignored, brackets are checked for balance and operators are
+/// checked for operands on both sides, so a successful result is always a
+/// well formed expression. A `-` or `+` where a value is expected, such as at
+/// the start or right after `(` or another operator, is read as a sign. A
+/// name followed by `(` is read as a function call, whose arguments are
+/// separated by `,`, unless it names a constant, and any other name as an
+/// identifier such as `pi`. Literals can carry a power of ten, as in
+/// `6.02e23`, or with `Settings::si_suffixes` an SI prefix, as in `4.7k`,
+/// can be written in hexadecimal, binary or octal, as in `0xFF`, `0b1010`
+/// or `0o17`, and can separate digits with `_`, as in `1_000_000`. The
+/// bitwise operators are `&`, `|`, `xor`, `~`, `<<` and `>>`, the
+/// comparisons `<`, `<=`, `==`, `!=`, `>=` and `>`, the boolean operators
+/// `and`, `or` and `not` and the unit conversion `to`, also written `in`. A
+/// leading `name =` marks an assignment and a leading `name(x, y) =` a
+/// function definition. A value followed by a name or a `(` without an
+/// operator between them, as in `2pi`, `2(3 + 4)` or `pi(2)`, is
+/// multiplied, binding tighter than `/` when the name is a unit, so that
+/// `100 km / 2 h` divides by `2 h`. Number literals are handed to the
+/// backend `N` as written, and every token is tagged with the `Span` of the
+/// characters it was read from.
+///
+/// # Returns
+/// Returns the tokens in the order they appear in the expression, or an
+/// `Error` describing the first problem found.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{tokenize, Operator, Span, Token};
+///
+/// let tokens = tokenize::<f64>("12 + 3").unwrap();
+///
+/// assert_eq!(tokens[0].value, Token::Number(12.0));
+/// assert_eq!(tokens[0].span, Span::new(0, 2));
+/// assert_eq!(tokens[1].value, Token::Operator(Operator::Add));
+/// assert_eq!(tokens[1].span, Span::new(3, 4));
+/// ```
+pub fn tokenize<N: Number>(expr: &str) -> Result<Vec<Spanned<Token<N>>>, Error> {
+    tokenize_with(expr, &Settings::default())
+}
+
+/// Splits an infix expression into a list of tokens, reading number literals
+/// with the given `settings`. See `tokenize`.
+pub fn tokenize_with<N: Number>(expr: &str, settings: &Settings<N>) -> Result<Vec<Spanned<Token<N>>>, Error> {
+    tokenize_in(expr, settings, &|name| Constant::from_name(name).is_some())
+}
+
+/// Splits an infix expression into a list of tokens like `tokenize_with`,
+/// reading a name followed by `(` as a value to multiply rather than as a
+/// function call when `is_value` returns `true` for it. Such a name is
+/// never a unit. A leading `name(x, y) =` is always a function definition.
+pub(crate) fn tokenize_in<N: Number>(expr: &str, settings: &Settings<N>, is_value: &dyn Fn(&str) -> bool) -> Result<Vec<Spanned<Token<N>>>, Error> {
+    let mut tokens: Vec<Spanned<Token<N>>> = Vec::new();
+    // Every open bracket, and whether it holds the arguments of a function.
+    let mut parens: Vec<(Span, bool)> = Vec::new();
+    let mut chars = expr.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let mut span = Span::new(i, i + c.len_utf8());
+
+        let token = match c {
+            '0' if radix_prefix(&chars).is_some() => {
+                let radix = radix_prefix(&chars).unwrap();
+                chars.next();
+                let span = Span::new(i, scan_radix_digits(&mut chars, i + 2));
+                let number = BigUint::parse_bytes(expr[i + 2..span.end].replace('_', "").as_bytes(), radix)
+                    .and_then(|value| N::from_literal(&value.to_string(), &settings.context))
+                    .ok_or(Error::InvalidNumber(span))?;
+                tokens.push(Spanned::new(Token::Number(number), span));
+                continue;
+            },
+            '0'..='9' | '.' => {
+                let mantissa = Span::new(i, scan_literal(&mut chars, span.end));
+                let (exponent, end) = match scan_exponent(&mut chars, &expr[mantissa.end..], settings.si_suffixes) {
+                    Some((exponent, length)) => (Some(exponent), mantissa.end + length),
+                    None => (None, mantissa.end),
+                };
+                let span = Span::new(i, end);
+                let literal = &expr[mantissa.range()].replace('_', "");
+                let number = match exponent {
+                    Some(exponent) => {
+                        let exponent = exponent.ok_or(Error::InvalidNumber(span))?;
+                        match N::from_scientific(literal, exponent, &settings.context) {
+                            None if N::from_literal(literal, &settings.context).is_some() => {
+                                return Err(Error::Overflow(span));
+                            },
+                            number => number,
+                        }
+                    },
+                    None => N::from_literal(literal, &settings.context),
+                };
+                tokens.push(Spanned::new(Token::Number(number.ok_or(Error::InvalidNumber(span))?), span));
+                continue;
+            },
+            _ if c.is_alphabetic() || c == '_' => {
+                let span = Span::new(i, scan_identifier(&mut chars, span.end));
+                let name = expr[span.range()].to_owned();
+                let token = if let Some(op) = word_operator(&name) {
+                    Token::Operator(op)
+                } else if next_is_open_bracket(&chars) && (!is_value(&name) || (tokens.is_empty() && defines_function(&expr[span.end..]))) {
+                    Token::Function(name)
+                } else {
+                    Token::Identifier(name)
+                };
+                tokens.push(Spanned::new(token, span));
+                continue;
+            },
+            '(' => {
+                let is_call = matches!(tokens.last(), Some(Spanned { value: Token::Function(_), .. }));
+                parens.push((span, is_call));
+                Token::Bracket('(')
+            },
+            ',' if matches!(parens.last(), Some((_, true))) => Token::Comma,
+            ')' => {
+                if parens.pop().is_none() {
+                    return Err(Error::MismatchedParens(span));
+                }
+                Token::Bracket(')')
+            },
+            '+' if expects_operand(tokens.last()) => Token::Operator(Operator::Pos),
+            '-' if expects_operand(tokens.last()) => Token::Operator(Operator::Neg),
+            '+' => Token::Operator(Operator::Add),
+            '-' => Token::Operator(Operator::Sub),
+            '*' if chars.next_if(|&(_, next)| next == '*').is_some() => {
+                span.end += 1;
+                Token::Operator(Operator::Pow)
+            },
+            '*' => Token::Operator(Operator::Mul),
+            '/' if chars.next_if(|&(_, next)| next == '/').is_some() => {
+                span.end += 1;
+                Token::Operator(Operator::FloorDiv)
+            },
+            '/' => Token::Operator(Operator::Div),
+            '%' if is_percent(&chars) => Token::Operator(Operator::Percent),
+            '%' => Token::Operator(Operator::Rem),
+            '^' => Token::Operator(Operator::Pow),
+            '!' if chars.next_if(|&(_, next)| next == '=').is_some() => {
+                span.end += 1;
+                Token::Operator(Operator::NotEqual)
+            },
+            '!' => Token::Operator(Operator::Factorial),
+            '~' => Token::Operator(Operator::BitNot),
+            '&' => Token::Operator(Operator::BitAnd),
+            '|' => Token::Operator(Operator::BitOr),
+            '<' | '>' if chars.next_if(|&(_, next)| next == c).is_some() => {
+                span.end += 1;
+                Token::Operator(if c == '<' { Operator::Shl } else { Operator::Shr })
+            },
+            '<' | '>' if chars.next_if(|&(_, next)| next == '=').is_some() => {
+                span.end += 1;
+                Token::Operator(if c == '<' { Operator::LessEqual } else { Operator::GreaterEqual })
+            },
+            '<' => Token::Operator(Operator::Less),
+            '>' => Token::Operator(Operator::Greater),
+            '=' if chars.next_if(|&(_, next)| next == '=').is_some() => {
+                span.end += 1;
+                Token::Operator(Operator::Equal)
+            },
+            '=' => Token::Assign,
+            ' ' | '\n' => continue,
+            _ => return Err(Error::BadToken(c, span))
+        };
+
+        tokens.push(Spanned::new(token, span));
+    }
+
+    if let Some((span, _)) = parens.pop() {
+        return Err(Error::MismatchedParens(span));
+    }
+
+    let is_unit = |name: &str| !is_value(name) && Unit::<N>::from_name(name, &settings.context).is_some();
+    let tokens = insert_implicit_multiplication(tokens, settings.implicit_multiplication, &is_unit);
+
+    check_syntax(&tokens, Span::new(0, expr.len()))?;
+
+    Ok(tokens)
+}
+
+/// Adds a multiplication between a number, a name or a `)` and a following
+/// name, function or `(`, as in `2pi`, `2sqrt(2)` or `(1 + 2)(3 + 4)`. The
+/// added operators have an empty span at the start of the right-hand side.
+/// Two numbers in a row are left alone, so `2 3` is still an error. A name
+/// for which `is_unit` returns `true` always gets `Operator::ImplicitMul`,
+/// whatever the `precedence`.
+fn insert_implicit_multiplication<N>(
+    tokens: Vec<Spanned<Token<N>>>,
+    precedence: ImplicitMultiplication,
+    is_unit: &dyn Fn(&str) -> bool,
+) -> Vec<Spanned<Token<N>>> {
+    let operator = match precedence {
+        ImplicitMultiplication::Ordinary => Operator::Mul,
+        ImplicitMultiplication::Tight => Operator::ImplicitMul,
+    };
+
+    let mut result: Vec<Spanned<Token<N>>> = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let ends_value = matches!(
+            result.last(),
+            Some(Spanned { value: Token::Number(_) | Token::Identifier(_) | Token::Bracket(')'), .. })
+        ) || matches!(result.last(), Some(Spanned { value: Token::Operator(op), .. }) if op.is_postfix());
+        let starts_value = matches!(token.value, Token::Identifier(_) | Token::Function(_) | Token::Bracket('('));
+
+        if ends_value && starts_value {
+            let start = token.span.start;
+            let operator = match &token.value {
+                Token::Identifier(name) if is_unit(name) => Operator::ImplicitMul,
+                _ => operator,
+            };
+            result.push(Spanned::new(Token::Operator(operator), Span::new(start, start)));
+        }
+        result.push(token);
+    }
+
+    result
+}
+
+/// Consumes the rest of a number literal, including `_` separators between
+/// digits, as in `1_000_000`.
+///
+/// # Returns
+/// Returns the byte offset right after the literal.
+fn scan_literal(chars: &mut Peekable<CharIndices>, mut end: usize) -> usize {
+    while let Some(&(i, c)) = chars.peek() {
+        let separator = c == '_' && chars.clone().nth(1).is_some_and(|(_, next)| next.is_ascii_digit());
+        if !c.is_ascii_digit() && c != '.' && !separator {
+            break;
+        }
+        end = i + c.len_utf8();
+        chars.next();
+    }
+
+    end
+}
+
+/// Returns the operator written as a word, such as `xor` or `and`.
+fn word_operator(name: &str) -> Option<Operator> {
+    match name {
+        "xor" => Some(Operator::BitXor),
+        "and" => Some(Operator::And),
+        "or" => Some(Operator::Or),
+        "not" => Some(Operator::Not),
+        "to" | "in" => Some(Operator::To),
+        _ => None,
+    }
+}
+
+/// Consumes the rest of a name made of letters, digits and `_`.
+///
+/// # Returns
+/// Returns the byte offset right after the name.
+fn scan_identifier(chars: &mut Peekable<CharIndices>, mut end: usize) -> usize {
+    while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_alphanumeric() || c == '_') {
+        end = i + c.len_utf8();
+    }
+
+    end
+}
+
+/// Returns `true` when the next character other than whitespace is `(`.
+fn next_is_open_bracket(chars: &Peekable<CharIndices>) -> bool {
+    let mut rest = chars.clone().map(|(_, c)| c).skip_while(|c| matches!(c, ' ' | '\n'));
+    rest.next() == Some('(')
+}
+
+/// Returns `true` when `rest` is a bracketed list followed by a `=`, as
+/// after the name of a function definition.
+fn defines_function(rest: &str) -> bool {
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => {
+                let after = rest[i + 1..].trim_start();
+                return after.starts_with('=') && !after.starts_with("==");
+            },
+            ')' => depth -= 1,
+            _ => {},
+        }
+    }
+
+    false
+}
+
+/// Returns the radix of a `0x`, `0b` or `0o` literal when the `0` just read
+/// starts one, that is when the prefix is followed by a digit of its radix.
+fn radix_prefix(chars: &Peekable<CharIndices>) -> Option<u32> {
+    let mut rest = chars.clone().map(|(_, c)| c);
+    let radix = match rest.next()? {
+        'x' | 'X' => 16,
+        'b' | 'B' => 2,
+        'o' | 'O' => 8,
+        _ => return None,
+    };
+
+    rest.next()?.is_digit(radix).then_some(radix)
+}
+
+/// Consumes the digits of a `0x`, `0b` or `0o` literal, along with any
+/// letter or `_` right after them so that `0b102` or `0xFG` are reported
+/// whole as invalid.
+///
+/// # Returns
+/// Returns the byte offset right after the literal.
+fn scan_radix_digits(chars: &mut Peekable<CharIndices>, mut end: usize) -> usize {
+    while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_ascii_alphanumeric() || c == '_') {
+        end = i + c.len_utf8();
+    }
+
+    end
+}
+
+/// Consumes the exponent of a number literal, either an `e` or `E` followed
+/// by an optionally signed whole number, as in `1.5e-3`, or with
+/// `si_suffixes` a single SI prefix, as in `4.7k`. `rest` is the expression
+/// right after the mantissa. A letter that starts a longer name, as the `e`
+/// of `2exp(1)` or the `p` of `2pi`, is left for the name.
+///
+/// # Returns
+/// Returns the power of ten, or `None` inside when it does not fit an
+/// `i32`, and the length of the exponent in bytes; or `None` when the
+/// literal has no exponent.
+fn scan_exponent(chars: &mut Peekable<CharIndices>, rest: &str, si_suffixes: bool) -> Option<(Option<i32>, usize)> {
+    let mut rest_chars = rest.chars();
+    let first = rest_chars.next()?;
+
+    if matches!(first, 'e' | 'E') {
+        let after = rest_chars.as_str();
+        let sign_length = usize::from(after.starts_with(['+', '-']));
+        let digits = after[sign_length..].bytes().take_while(u8::is_ascii_digit).count();
+        let ends_name = after[sign_length + digits..].starts_with(|c: char| c.is_alphanumeric() || c == '_');
+
+        if digits > 0 && !ends_name {
+            let length = 1 + sign_length + digits;
+            for _ in 0..length {
+                chars.next();
+            }
+            return Some((rest[1..length].parse().ok(), length));
+        }
+    }
+
+    if si_suffixes && !rest_chars.as_str().starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+        let exponent = match first {
+            'T' => 12,
+            'G' => 9,
+            'M' => 6,
+            'k' => 3,
+            'm' => -3,
+            'u' | 'µ' => -6,
+            'n' => -9,
+            'p' => -12,
+            'f' => -15,
+            _ => return None,
+        };
+        chars.next();
+        return Some((Some(exponent), first.len_utf8()));
+    }
+
+    None
+}
+
+/// Returns `true` when the `%` just read is a percentage rather than a
+/// remainder, that is when no value follows it. A `+` or `-` after it is a
+/// sign only when it is set apart from the `%` and attached to the value
+/// after it, as in `7 % -3`; `10% - 3` and `10%-3` subtract.
+fn is_percent(chars: &Peekable<CharIndices>) -> bool {
+    let mut rest = chars.clone().map(|(_, c)| c).peekable();
+    let mut spaced = false;
+    while rest.next_if(|&c| matches!(c, ' ' | '\n')).is_some() {
+        spaced = true;
+    }
+
+    match rest.next() {
+        None => true,
+        Some('+' | '-') => !spaced || rest.peek().map_or(true, |&c| matches!(c, ' ' | '\n')),
+        Some(c) => !(c.is_alphanumeric() || matches!(c, '.' | '(' | '_')),
+    }
+}
+
+/// Returns `true` when the token after `previous` has to be a value, which
+/// is what makes a `-` a negation rather than a subtraction.
+fn expects_operand<N>(previous: Option<&Spanned<Token<N>>>) -> bool {
+    match previous {
+        None => true,
+        Some(Spanned { value: Token::Operator(op), .. }) => !op.is_postfix(),
+        Some(token) => matches!(token.value, Token::Bracket('(') | Token::Comma | Token::Assign),
+    }
+}
+
+/// Returns `true` when the tokens end with a function name and its `(`, so
+/// that a `)` would close a call without arguments.
+fn closes_empty_call<N>(before: &[Spanned<Token<N>>]) -> bool {
+    matches!(before, [.., Spanned { value: Token::Function(_), .. }, Spanned { value: Token::Bracket('('), .. }])
+}
+
+/// Returns `true` for the tokens a `=` can follow: a single name, or a
+/// function name with a list of parameter names.
+fn is_assignment_target<N>(before: &[Spanned<Token<N>>]) -> bool {
+    match before {
+        [Spanned { value: Token::Identifier(_), .. }] => true,
+        [Spanned { value: Token::Function(_), .. }, Spanned { value: Token::Bracket('('), .. }, parameters @ .., Spanned { value: Token::Bracket(')'), .. }] => {
+            parameters.iter().enumerate().all(|(index, token)| match token.value {
+                Token::Identifier(_) => index % 2 == 0,
+                Token::Comma => index % 2 == 1,
+                _ => false,
+            })
+        },
+        _ => false,
+    }
+}
+
+/// Walks the tokens checking that values and operators alternate.
+fn check_syntax<N>(tokens: &[Spanned<Token<N>>], whole: Span) -> Result<(), Error> {
+    let mut expect_operand = true;
+    let mut previous: Option<&Spanned<Token<N>>> = None;
+
+    for (index, token) in tokens.iter().enumerate() {
+        match &token.value {
+            Token::Number(_) | Token::Identifier(_) => {
+                if !expect_operand {
+                    return Err(Error::MissingOperator(token.span));
+                }
+                expect_operand = false;
+            },
+            Token::Bracket('(') | Token::Function(_) => {
+                if !expect_operand {
+                    return Err(Error::MissingOperator(token.span));
+                }
+            },
+            // A call without arguments, left for the evaluator to check.
+            Token::Bracket(_) if closes_empty_call(&tokens[..index]) => {
+                expect_operand = false;
+            },
+            Token::Bracket(_) | Token::Comma => {
+                if expect_operand {
+                    return Err(match previous {
+                        Some(Spanned { value: Token::Operator(op), span }) => Error::TrailingOperator(*op, *span),
+                        Some(open) => Error::EmptyExpression(open.span.to(token.span)),
+                        None => Error::EmptyExpression(token.span),
+                    });
+                }
+                expect_operand = matches!(token.value, Token::Comma);
+            },
+            Token::Operator(op) if op.is_unary() => {},
+            Token::Operator(op) if op.is_postfix() => {
+                if expect_operand {
+                    return Err(Error::MissingOperand(*op, token.span));
+                }
+            },
+            Token::Operator(op) => {
+                if expect_operand {
+                    return Err(Error::MissingOperand(*op, token.span));
+                }
+                expect_operand = true;
+            },
+            Token::Assign => {
+                if !is_assignment_target(&tokens[..index]) {
+                    return Err(Error::InvalidAssignment(token.span));
+                }
+                expect_operand = true;
+            },
+            Token::Call(..) | Token::JumpUnless(_) | Token::Jump(_) => {},
+        }
+        previous = Some(token);
+    }
+
+    match previous {
+        None => Err(Error::EmptyExpression(whole)),
+        Some(Spanned { value: Token::Operator(op), span }) if !op.is_postfix() => Err(Error::TrailingOperator(*op, *span)),
+        Some(Spanned { value: Token::Assign, span }) => Err(Error::EmptyExpression(*span)),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt;
+
+use crate::engine::number::{ArithmeticError, Number};
+use crate::engine::value::Value;
+
+/// The SI base units, in the order `Dimension` stores their powers.
+pub const BASE_UNITS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// The powers of the SI base units a quantity is made of, such as
+/// `m^1 s^-1` for a speed. Quantities can only be added, compared or
+/// converted into one another when their dimensions are the same.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Dimension(pub [i32; 7]);
+
+impl Dimension {
+    /// Returns `true` for pure numbers, such as the ratio of two lengths.
+    pub fn is_dimensionless(&self) -> bool {
+        self.0.iter().all(|power| *power == 0)
+    }
+
+    fn combine(&self, other: &Self, sign: i32) -> Result<Self, ArithmeticError> {
+        let mut powers = self.0;
+        for (power, other) in powers.iter_mut().zip(other.0) {
+            *power = add_power(*power, other, sign)?;
+        }
+
+        Ok(Dimension(powers))
+    }
+
+    fn scale(&self, exponent: i32) -> Result<Self, ArithmeticError> {
+        let mut powers = self.0;
+        for power in powers.iter_mut() {
+            *power = power.checked_mul(exponent).ok_or(ArithmeticError::Overflow)?;
+        }
+
+        Ok(Dimension(powers))
+    }
+}
+
+/// Returns `power + sign * other`, failing when it does not fit in an `i32`.
+fn add_power(power: i32, other: i32, sign: i32) -> Result<i32, ArithmeticError> {
+    other.checked_mul(sign).and_then(|other| power.checked_add(other)).ok_or(ArithmeticError::Overflow)
+}
+
+/// A unit a value can be measured in, either one of the named units of the
+/// registry, as in `km`, or a product of their powers, as in `km/h`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::units::Unit;
+///
+/// let foot = Unit::<f64>::from_name("ft", &()).unwrap();
+/// let hour = Unit::<f64>::from_name("h", &()).unwrap();
+///
+/// assert_eq!(foot.factor, 0.3048);
+/// assert_eq!(foot.div(&hour, &()).unwrap().to_string(), "ft/h");
+/// assert_eq!(Unit::<f64>::from_name("furlong", &()), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit<N = f64> {
+    /// The size of the unit in SI base units, such as `0.3048` for `ft`.
+    pub factor: N,
+    /// The powers of the SI base units the unit is made of.
+    pub dimension: Dimension,
+    /// The named units with their powers, in the order they were written.
+    terms: Vec<(String, i32)>,
+}
+
+/// A unit of the registry: its symbol, its size in SI base units as a
+/// decimal literal, its dimension and whether it takes an SI prefix.
+struct Definition {
+    symbol: &'static str,
+    factor: &'static str,
+    dimension: [i32; 7],
+    prefixed: bool,
+}
+
+const fn unit(symbol: &'static str, factor: &'static str, dimension: [i32; 7], prefixed: bool) -> Definition {
+    Definition { symbol, factor, dimension, prefixed }
+}
+
+const LENGTH: [i32; 7] = [1, 0, 0, 0, 0, 0, 0];
+const MASS: [i32; 7] = [0, 1, 0, 0, 0, 0, 0];
+const TIME: [i32; 7] = [0, 0, 1, 0, 0, 0, 0];
+const VOLUME: [i32; 7] = [3, 0, 0, 0, 0, 0, 0];
+const SPEED: [i32; 7] = [1, 0, -1, 0, 0, 0, 0];
+
+/// The units that can be written by name. Temperatures are only available
+/// in kelvin, since units with an offset such as degrees Celsius do not
+/// scale like the others.
+const UNITS: &[Definition] = &[
+    unit("m", "1", LENGTH, true),
+    unit("g", "0.001", MASS, true),
+    unit("s", "1", TIME, true),
+    unit("A", "1", [0, 0, 0, 1, 0, 0, 0], true),
+    unit("K", "1", [0, 0, 0, 0, 1, 0, 0], true),
+    unit("mol", "1", [0, 0, 0, 0, 0, 1, 0], true),
+    unit("cd", "1", [0, 0, 0, 0, 0, 0, 1], true),
+    unit("Hz", "1", [0, 0, -1, 0, 0, 0, 0], true),
+    unit("N", "1", [1, 1, -2, 0, 0, 0, 0], true),
+    unit("Pa", "1", [-1, 1, -2, 0, 0, 0, 0], true),
+    unit("J", "1", [2, 1, -2, 0, 0, 0, 0], true),
+    unit("W", "1", [2, 1, -3, 0, 0, 0, 0], true),
+    unit("C", "1", [0, 0, 1, 1, 0, 0, 0], true),
+    unit("V", "1", [2, 1, -3, -1, 0, 0, 0], true),
+    unit("L", "0.001", VOLUME, true),
+    unit("min", "60", TIME, false),
+    unit("h", "3600", TIME, false),
+    unit("day", "86400", TIME, false),
+    unit("inch", "0.0254", LENGTH, false),
+    unit("ft", "0.3048", LENGTH, false),
+    unit("yd", "0.9144", LENGTH, false),
+    unit("mi", "1609.344", LENGTH, false),
+    unit("oz", "0.028349523125", MASS, false),
+    unit("lb", "0.45359237", MASS, false),
+    unit("gal", "0.003785411784", VOLUME, false),
+    unit("mph", "0.44704", SPEED, false),
+];
+
+/// The SI prefixes with their powers of ten.
+const PREFIXES: &[(&str, i32)] = &[
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("c", -2),
+    ("m", -3),
+    ("u", -6),
+    ("µ", -6),
+    ("n", -9),
+    ("p", -12),
+];
+
+impl<N: Number> Unit<N> {
+    /// Returns the unit of plain numbers, which has no name.
+    pub fn one(context: &N::Context) -> Option<Self> {
+        Some(Unit { factor: N::from_literal("1", context)?, dimension: Dimension::default(), terms: Vec::new() })
+    }
+
+    /// Looks up a unit by the name used in expressions: an SI unit such as
+    /// `m`, `s` or `J`, optionally behind an SI prefix as in `km` or `ms`,
+    /// or a common imperial unit such as `ft`, `lb` or `mph`. Inches are
+    /// written `inch`, since `in` converts.
+    ///
+    /// # Returns
+    /// Returns `None` for unknown names and for units whose size the
+    /// backend cannot represent, such as `ft` for the whole numbers of
+    /// `Word`.
+    pub fn from_name(name: &str, context: &N::Context) -> Option<Self> {
+        let exact = UNITS.iter().find(|definition| definition.symbol == name).map(|definition| (definition, 0));
+        let (definition, exponent) = exact.or_else(|| {
+            PREFIXES.iter().find_map(|(prefix, exponent)| {
+                let symbol = name.strip_prefix(prefix)?;
+                let definition = UNITS.iter().find(|definition| definition.prefixed && definition.symbol == symbol)?;
+                Some((definition, *exponent))
+            })
+        })?;
+
+        let factor = match exponent {
+            0 => N::from_literal(definition.factor, context)?,
+            exponent => N::from_scientific(definition.factor, exponent, context)?,
+        };
+        Some(Unit { factor, dimension: Dimension(definition.dimension), terms: vec![(name.to_owned(), 1)] })
+    }
+
+    /// Returns the product of two units, such as `N*m`.
+    pub fn mul(&self, rhs: &Self, context: &N::Context) -> Result<Self, ArithmeticError> {
+        Ok(Unit { factor: self.factor.mul(&rhs.factor, context)?, dimension: self.dimension.combine(&rhs.dimension, 1)?, terms: merge(&self.terms, &rhs.terms, 1)? })
+    }
+
+    /// Returns the quotient of two units, such as `km/h`.
+    pub fn div(&self, rhs: &Self, context: &N::Context) -> Result<Self, ArithmeticError> {
+        Ok(Unit { factor: self.factor.div(&rhs.factor, context)?, dimension: self.dimension.combine(&rhs.dimension, -1)?, terms: merge(&self.terms, &rhs.terms, -1)? })
+    }
+
+    /// Returns the unit raised to a whole power, such as `m^2`.
+    pub fn powi(&self, exponent: i32, context: &N::Context) -> Result<Self, ArithmeticError> {
+        let power = N::from_literal(&exponent.to_string(), context).ok_or(ArithmeticError::Overflow)?;
+        let terms = match exponent {
+            0 => Vec::new(),
+            _ => self.terms
+                .iter()
+                .map(|(name, power)| power.checked_mul(exponent).map(|power| (name.clone(), power)))
+                .collect::<Option<_>>()
+                .ok_or(ArithmeticError::Overflow)?,
+        };
+
+        Ok(Unit { factor: self.factor.pow(&power, context)?, dimension: self.dimension.scale(exponent)?, terms })
+    }
+}
+
+/// Adds the powers of `rhs`, times `sign`, to those of `terms`, dropping
+/// the names whose powers cancel out.
+fn merge(terms: &[(String, i32)], rhs: &[(String, i32)], sign: i32) -> Result<Vec<(String, i32)>, ArithmeticError> {
+    let mut merged = terms.to_vec();
+    for (name, power) in rhs {
+        match merged.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, existing)) => *existing = add_power(*existing, *power, sign)?,
+            None => merged.push((name.clone(), add_power(0, *power, sign)?)),
+        }
+    }
+    merged.retain(|(_, power)| *power != 0);
+
+    Ok(merged)
+}
+
+/// Writes the unit the way it can be typed back, as in `kg*m/s^2`.
+impl<N> fmt::Display for Unit<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let term = |(name, power): &(String, i32), sign: i64| match i64::from(*power) * sign {
+            1 => name.clone(),
+            power => format!("{}^{}", name, power),
+        };
+        let numerator: Vec<String> = self.terms.iter().filter(|(_, power)| *power > 0).map(|t| term(t, 1)).collect();
+        let denominator: Vec<String> = self.terms.iter().filter(|(_, power)| *power < 0).map(|t| term(t, -1)).collect();
+
+        match (numerator.is_empty(), denominator.len()) {
+            (_, 0) => write!(f, "{}", numerator.join("*")),
+            (true, _) => {
+                let inverse: Vec<String> = self.terms.iter().map(|t| term(t, 1)).collect();
+                write!(f, "{}", inverse.join("*"))
+            },
+            (false, 1) => write!(f, "{}/{}", numerator.join("*"), denominator[0]),
+            (false, _) => write!(f, "{}/({})", numerator.join("*"), denominator.join("*")),
+        }
+    }
+}
+
+/// A number of some unit, such as `3 ft`, as produced by writing a value
+/// next to the name of a unit.
+///
+/// Quantities keep the unit they were written in: sums take the unit of
+/// their left-hand side, and `to` or `in` express a quantity in another
+/// unit of the same dimension. A unit written after a number binds tighter
+/// than `/`, so `2 km / 30 min` divides by the whole `30 min`.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve, Error, Span};
+///
+/// assert_eq!(resolve("3 ft + 20 cm in m"), Ok("1.1144 m".to_owned()));
+/// assert_eq!(resolve("2 km / 30 min"), Ok("0.06666666666666667 km/min".to_owned()));
+/// assert_eq!(resolve("1 m + 1 s"), Err(Error::IncompatibleUnits(Span::new(0, 9))));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantity<N = f64> {
+    /// How many of `unit` the quantity holds.
+    pub value: N,
+    /// The unit the quantity is measured in.
+    pub unit: Unit<N>,
+}
+
+impl<N: Number> Quantity<N> {
+    pub fn new(value: N, unit: Unit<N>) -> Self {
+        Quantity { value, unit }
+    }
+
+    /// Returns the number of `unit`s the quantity holds, `unit` having the
+    /// same dimension.
+    pub fn value_in(&self, unit: &Unit<N>, context: &N::Context) -> Result<N, ArithmeticError> {
+        if self.unit.terms == unit.terms {
+            return Ok(self.value.clone());
+        }
+        self.value.mul(&self.unit.factor.div(&unit.factor, context)?, context)
+    }
+
+    /// Multiplies two quantities, multiplying their units.
+    ///
+    /// # Returns
+    /// Returns a quantity, or a plain number when the dimensions cancel
+    /// out, as in `1 km / 1 m`.
+    pub fn mul(&self, rhs: &Self, context: &N::Context) -> Result<Value<N>, ArithmeticError> {
+        Quantity::new(self.value.mul(&rhs.value, context)?, self.unit.mul(&rhs.unit, context)?).simplified(context)
+    }
+
+    /// Divides two quantities, dividing their units. See `mul`.
+    pub fn div(&self, rhs: &Self, context: &N::Context) -> Result<Value<N>, ArithmeticError> {
+        Quantity::new(self.value.div(&rhs.value, context)?, self.unit.div(&rhs.unit, context)?).simplified(context)
+    }
+
+    /// Raises the quantity to a whole power, raising its unit too. See `mul`.
+    pub fn powi(&self, exponent: i32, context: &N::Context) -> Result<Value<N>, ArithmeticError> {
+        let power = N::from_literal(&exponent.to_string(), context).ok_or(ArithmeticError::Overflow)?;
+
+        Quantity::new(self.value.pow(&power, context)?, self.unit.powi(exponent, context)?).simplified(context)
+    }
+
+    fn simplified(self, context: &N::Context) -> Result<Value<N>, ArithmeticError> {
+        if self.unit.dimension.is_dimensionless() {
+            self.value.mul(&self.unit.factor, context).map(Value::Number)
+        } else {
+            Ok(Value::Quantity(self))
+        }
+    }
+}
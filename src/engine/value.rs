@@ -0,0 +1,53 @@
+use crate::engine::number::Number;
+use crate::engine::units::Quantity;
+
+/// A value an expression can evaluate to: a number of the backend `N`, the
+/// boolean produced by a comparison such as `x > 5`, or a number with a
+/// unit such as `3 ft`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<N = f64> {
+    Number(N),
+    Boolean(bool),
+    Quantity(Quantity<N>),
+}
+
+impl<N: Number> Value<N> {
+    /// Returns the number, if the value is one.
+    pub fn number(&self) -> Option<&N> {
+        match self {
+            Self::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean, if the value is one.
+    pub fn boolean(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(boolean) => Some(*boolean),
+            _ => None,
+        }
+    }
+
+    /// Returns the quantity, if the value has a unit.
+    pub fn quantity(&self) -> Option<&Quantity<N>> {
+        match self {
+            Self::Quantity(quantity) => Some(quantity),
+            _ => None,
+        }
+    }
+}
+
+impl<N> From<N> for Value<N> {
+    fn from(number: N) -> Self {
+        Self::Number(number)
+    }
+}
+
+/// Reads the names `true` and `false`.
+pub fn boolean_literal(name: &str) -> Option<bool> {
+    match name {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,277 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::engine::functions::{self, Function};
+use crate::engine::number::{ArithmeticError, Number, Remainder};
+
+/// The number of bits a `Word` holds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WordSize {
+    Bits8,
+    Bits16,
+    Bits32,
+    #[default]
+    Bits64,
+}
+
+impl WordSize {
+    /// Every word size, from the smallest to the largest.
+    pub const ALL: [WordSize; 4] = [Self::Bits8, Self::Bits16, Self::Bits32, Self::Bits64];
+
+    /// Returns the number of bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::Bits8 => 8,
+            Self::Bits16 => 16,
+            Self::Bits32 => 32,
+            Self::Bits64 => 64,
+        }
+    }
+}
+
+/// The settings every `Word` operation wraps with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WordContext {
+    pub size: WordSize,
+    /// Whether the highest bit is a sign bit, as in two's complement, or
+    /// counts like the others.
+    pub signed: bool,
+}
+
+impl Default for WordContext {
+    fn default() -> Self {
+        WordContext { size: WordSize::default(), signed: true }
+    }
+}
+
+/// A base a `Word` can be written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    Hexadecimal,
+    Decimal,
+    Octal,
+    Binary,
+}
+
+impl Radix {
+    /// Every radix, in the order a programmer's calculator lists them.
+    pub const ALL: [Radix; 4] = [Self::Hexadecimal, Self::Decimal, Self::Octal, Self::Binary];
+
+    /// Returns the base itself, such as `16`.
+    pub fn base(&self) -> u32 {
+        match self {
+            Self::Hexadecimal => 16,
+            Self::Decimal => 10,
+            Self::Octal => 8,
+            Self::Binary => 2,
+        }
+    }
+
+    /// Returns the short name shown next to a value, such as `HEX`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Hexadecimal => "HEX",
+            Self::Decimal => "DEC",
+            Self::Octal => "OCT",
+            Self::Binary => "BIN",
+        }
+    }
+}
+
+/// A fixed-size integer backend for programming work.
+///
+/// Every result wraps around to `WordContext::size` bits, read as a signed
+/// two's complement value or as an unsigned one depending on
+/// `WordContext::signed`, the way the integers of C or Rust do. Division
+/// truncates toward zero and literals with a fractional part are rejected.
+///
+/// # Example
+/// ```
+/// use rustcalculator::engine::{resolve_with, Settings};
+/// use rustcalculator::engine::word::{Radix, Word, WordContext, WordSize};
+///
+/// let context = WordContext { size: WordSize::Bits8, signed: true };
+/// assert_eq!(resolve_with::<Word>("127 + 1", &Settings::new(context)), Ok("-128".to_owned()));
+///
+/// let context = WordContext { size: WordSize::Bits8, signed: false };
+/// assert_eq!(resolve_with::<Word>("~0x0F", &Settings::new(context)), Ok("240".to_owned()));
+///
+/// let value = Word::new(-1, &context);
+/// assert_eq!(value.to_radix(Radix::Hexadecimal, &context), "0xFF");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Word(i128);
+
+impl Word {
+    /// Creates a word from any integer, keeping only its lowest bits.
+    pub fn new(value: i128, context: &WordContext) -> Self {
+        let bits = context.size.bits();
+        let pattern = value as u128 & mask(context);
+
+        if context.signed && pattern >> (bits - 1) == 1 {
+            Word(pattern as i128 - (1i128 << bits))
+        } else {
+            Word(pattern as i128)
+        }
+    }
+
+    /// Returns the value the bits stand for.
+    pub fn value(&self) -> i128 {
+        self.0
+    }
+
+    /// Returns the bits of the word, the lowest one being bit `0`.
+    pub fn pattern(&self, context: &WordContext) -> u64 {
+        (self.0 as u128 & mask(context)) as u64
+    }
+
+    /// Returns whether bit `index` is set, the lowest one being bit `0`.
+    pub fn bit(&self, index: u32, context: &WordContext) -> bool {
+        self.pattern(context) >> index & 1 == 1
+    }
+
+    /// Flips bit `index`, the lowest one being bit `0`.
+    pub fn toggle_bit(&self, index: u32, context: &WordContext) -> Self {
+        Word::new(self.0 ^ (1i128 << index), context)
+    }
+
+    /// Writes the word in the given radix. Decimals show the value, with its
+    /// sign for signed words; the other radixes show the bits, prefixed the
+    /// way the tokenizer reads them back, as in `0xFF`.
+    pub fn to_radix(&self, radix: Radix, context: &WordContext) -> String {
+        let pattern = self.pattern(context);
+        match radix {
+            Radix::Hexadecimal => format!("0x{:X}", pattern),
+            Radix::Decimal => self.0.to_string(),
+            Radix::Octal => format!("0o{:o}", pattern),
+            Radix::Binary => format!("0b{:b}", pattern),
+        }
+    }
+}
+
+impl Number for Word {
+    type Context = WordContext;
+
+    fn from_literal(literal: &str, context: &WordContext) -> Option<Self> {
+        let (whole, fraction) = literal.split_once('.').unwrap_or((literal, ""));
+        if (whole.is_empty() && fraction.is_empty()) || !fraction.chars().all(|c| c == '0') {
+            return None;
+        }
+
+        Self::from_integer(&format!("0{}", whole).parse().ok()?, context)
+    }
+
+    fn add(&self, rhs: &Self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        Ok(Word::new(self.0 + rhs.0, context))
+    }
+
+    fn sub(&self, rhs: &Self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        Ok(Word::new(self.0 - rhs.0, context))
+    }
+
+    fn mul(&self, rhs: &Self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        Ok(Word::new(self.0.wrapping_mul(rhs.0), context))
+    }
+
+    /// Truncates toward zero, as in C.
+    fn div(&self, rhs: &Self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Ok(Word::new(self.0 / rhs.0, context))
+    }
+
+    fn neg(&self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        Ok(Word::new(-self.0, context))
+    }
+
+    /// A negative exponent gives the truncated reciprocal, which is zero
+    /// unless the base is `1` or `-1`.
+    fn pow(&self, rhs: &Self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        if rhs.0 < 0 {
+            return match self.0 {
+                0 => Err(ArithmeticError::DivisionByZero),
+                1 => Ok(*self),
+                -1 => Ok(Word::new(if rhs.0 % 2 == 0 { 1 } else { -1 }, context)),
+                _ => Ok(Word(0)),
+            };
+        }
+
+        let (mut base, mut exponent, mut result) = (self.0, rhs.0, 1i128);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = Word::new(result.wrapping_mul(base), context).0;
+            }
+            base = Word::new(base.wrapping_mul(base), context).0;
+            exponent >>= 1;
+        }
+
+        Ok(Word::new(result, context))
+    }
+
+    fn rem(&self, rhs: &Self, mode: Remainder, context: &WordContext) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let remainder = match mode {
+            Remainder::Truncated => self.0 % rhs.0,
+            Remainder::Floored => self.0.mod_floor(&rhs.0),
+        };
+        Ok(Word::new(remainder, context))
+    }
+
+    fn floor_div(&self, rhs: &Self, context: &WordContext) -> Result<Self, ArithmeticError> {
+        if rhs.0 == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        Ok(Word::new(Integer::div_floor(&self.0, &rhs.0), context))
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+
+    /// Fractions are truncated toward zero, so `sqrt(10)` is `3`.
+    fn from_f64(value: f64, context: &WordContext) -> Option<Self> {
+        Self::from_integer(&BigInt::from_f64(value.trunc())?, context)
+    }
+
+    fn is_exact(&self) -> bool {
+        true
+    }
+
+    fn to_integer(&self) -> Option<BigInt> {
+        Some(BigInt::from(self.0))
+    }
+
+    /// Keeps the lowest bits of the two's complement form of `value`.
+    fn from_integer(value: &BigInt, context: &WordContext) -> Option<Self> {
+        let low = value & BigInt::from(mask(context));
+        Some(Word::new(low.to_i128()?, context))
+    }
+
+    /// Rounding a word leaves it unchanged.
+    fn call(function: Function, args: &[Self], context: &WordContext) -> Result<Self, ArithmeticError> {
+        match function {
+            Function::Floor | Function::Ceil | Function::Round => Ok(args[0]),
+            _ => functions::approximate(function, args, context),
+        }
+    }
+
+    fn format(&self, _context: &WordContext) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Returns a pattern with the bits of the word size set.
+fn mask(context: &WordContext) -> u128 {
+    (1u128 << context.size.bits()) - 1
+}
@@ -1 +1,4 @@
-pub mod calculator;
\ No newline at end of file
+pub mod engine;
+
+#[cfg(feature = "gui")]
+pub mod calculator;
@@ -1,34 +1,41 @@
-use calculator::Calculator;
+use rustcalculator::calculator::Calculator;
+use rustcalculator::calculator::screen_settings::{get_app_width, get_app_height, get_programmer_app_height};
 
-use iced::window::settings::PlatformSpecific;
 use iced::{Application, Settings, Size};
 use iced::window::{self, Level, Position};
 
-pub mod calculator;
-use calculator::screen_settings::{get_app_width, get_app_height};
-
 pub fn main() -> iced::Result {
     let settings: Settings<()> = Settings {
         window: window::Settings { 
             size: Size { width: get_app_width(), height: get_app_height() }, 
             position: Position::Centered, 
             min_size: Some(Size { width: get_app_width(), height: get_app_height() }), 
-            max_size: Some(Size { width: get_app_width(), height: get_app_height() }), 
+            max_size: Some(Size { width: get_app_width(), height: get_programmer_app_height() }), 
             visible: true, 
             resizable: false, 
             decorations: true, 
             transparent: false, 
             level: Level::Normal, 
             icon: None, 
-            platform_specific: PlatformSpecific { 
-                title_hidden: true, 
-                titlebar_transparent: true, 
-                fullsize_content_view: false 
-            }, 
+            platform_specific: platform_specific(), 
             exit_on_close_request: true,
         },
         ..Default::default()
     };
 
     Calculator::run(settings)
-}
\ No newline at end of file
+}
+
+#[cfg(target_os = "macos")]
+fn platform_specific() -> window::settings::PlatformSpecific {
+    window::settings::PlatformSpecific { 
+        title_hidden: true, 
+        titlebar_transparent: true, 
+        fullsize_content_view: false 
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn platform_specific() -> window::settings::PlatformSpecific {
+    Default::default()
+}
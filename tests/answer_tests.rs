@@ -0,0 +1,51 @@
+use rustcalculator::engine::environment::answer_age;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::{Environment, Error, Span};
+
+#[test]
+fn test_answer_names() {
+    assert_eq!(answer_age("ans"), Some(0));
+    assert_eq!(answer_age("ans1"), Some(1));
+    assert_eq!(answer_age("ans12"), Some(12));
+    assert_eq!(answer_age("answer"), None);
+    assert_eq!(answer_age("an"), None);
+}
+
+#[test]
+fn test_answer_history() {
+    let mut environment = Environment::<f64>::default();
+
+    assert_eq!(environment.resolve("ans"), Err(Error::UnknownIdentifier("ans".to_owned(), Span::new(0, 3))));
+
+    environment.resolve("2 + 3").unwrap();
+    environment.resolve("ans * 10").unwrap();
+    environment.resolve("x = 7").unwrap();
+
+    assert_eq!(environment.resolve("ans + ans1 + ans2").unwrap(), "62");
+    assert_eq!(environment.resolve("ans").unwrap(), "62");
+    assert_eq!(environment.resolve("ans4").unwrap(), "5");
+    assert_eq!(environment.resolve("ans10"), Err(Error::UnknownIdentifier("ans10".to_owned(), Span::new(0, 5))));
+
+    let history: Vec<f64> = environment.history().filter_map(|value| value.number().copied()).collect();
+    assert_eq!(history, vec![5.0, 62.0, 62.0, 7.0, 50.0, 5.0]);
+}
+
+#[test]
+fn test_answer_failed_evaluation() {
+    let mut environment = Environment::<Integer>::default();
+
+    environment.resolve("2 ^ 100").unwrap();
+    assert!(environment.resolve("1 / 0").is_err());
+    assert_eq!(environment.resolve("ans - 2 ^ 100").unwrap(), "0");
+}
+
+#[test]
+fn test_answer_cannot_be_assigned() {
+    let mut environment = Environment::<f64>::default();
+
+    assert_eq!(environment.resolve("ans = 3"), Err(Error::InvalidAssignment(Span::new(0, 3))));
+    assert_eq!(environment.resolve("ans2 = 3"), Err(Error::InvalidAssignment(Span::new(0, 4))));
+
+    environment.clear_history();
+    assert_eq!(environment.answer(0), None);
+}
@@ -0,0 +1,60 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Operator, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_bitwise_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("1 + 2 << 3 & 7").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(1.0), Token::Number(2.0), Token::Operator(Operator::Add),
+        Token::Number(3.0), Token::Operator(Operator::Shl),
+        Token::Number(7.0), Token::Operator(Operator::BitAnd)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_bitwise_expressions() {
+    assert_eq!(engine::resolve("12 & 10").unwrap(), "8");
+    assert_eq!(engine::resolve("12 | 10").unwrap(), "14");
+    assert_eq!(engine::resolve("6 xor 3").unwrap(), "5");
+    assert_eq!(engine::resolve("~5").unwrap(), "-6");
+    assert_eq!(engine::resolve("1 << 10").unwrap(), "1024");
+    assert_eq!(engine::resolve("-8 >> 1").unwrap(), "-4");
+    assert_eq!(engine::resolve("-1 & 0xF0").unwrap(), "240");
+    assert_eq!(engine::resolve("0xF0 | 0x0F").unwrap(), "255");
+}
+
+#[test]
+fn test_bitwise_precedence() {
+    assert_eq!(engine::resolve("1 + 2 << 3 & 7").unwrap(), "0");
+    assert_eq!(engine::resolve("1 | 2 xor 3 & 6").unwrap(), "1");
+    assert_eq!(engine::resolve("2 * 3 << 1").unwrap(), "12");
+    assert_eq!(engine::resolve("~1 + 1").unwrap(), "-1");
+}
+
+#[test]
+fn test_bitwise_stays_exact() {
+    assert_eq!(engine::resolve_as::<Integer>("1 << 100").unwrap(), "1267650600228229401496703205376");
+    assert_eq!(engine::resolve_as::<Integer>("(1 << 100) >> 99").unwrap(), "2");
+    assert_eq!(engine::resolve_as::<Rational>("6 / 2 & 7").unwrap(), "3");
+    assert_eq!(engine::resolve_as::<Decimal>("2.0 xor 3").unwrap(), "1");
+}
+
+#[test]
+fn test_bitwise_errors() {
+    assert_eq!(engine::resolve("1.5 & 1"), Err(Error::NotAnInteger(Span::new(0, 7))));
+    assert_eq!(engine::resolve_as::<Rational>("~(1 / 2)"), Err(Error::NotAnInteger(Span::new(0, 7))));
+    assert_eq!(engine::resolve("1 << -1"), Err(Error::Undefined(Span::new(0, 7))));
+    assert_eq!(engine::resolve_as::<Integer>("1 << (1 << 30)"), Err(Error::Overflow(Span::new(0, 13))));
+    assert_eq!(engine::resolve("1 <<< 2"), Err(Error::MissingOperand(Operator::Less, Span::new(4, 5))));
+    assert_eq!(engine::resolve("& 1"), Err(Error::MissingOperand(Operator::BitAnd, Span::new(0, 1))));
+}
@@ -0,0 +1,6 @@
+use rustcalculator::engine::{Spanned, Token};
+
+/// Drops the spans of the tokens, keeping only their values.
+pub fn values(tokens: Vec<Spanned<Token>>) -> Vec<Token> {
+    tokens.into_iter().map(|token| token.value).collect()
+}
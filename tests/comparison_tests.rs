@@ -0,0 +1,72 @@
+use rustcalculator::engine;
+use rustcalculator::engine::environment::Environment;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Operator, Settings, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_if_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("if(1 < 2, 3, 4)").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(1.0), Token::Number(2.0), Token::Operator(Operator::Less),
+        Token::JumpUnless(6), Token::Number(3.0), Token::Jump(7), Token::Number(4.0)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_comparisons() {
+    assert_eq!(engine::resolve("3 > 2").unwrap(), "true");
+    assert_eq!(engine::resolve("2 <= 1").unwrap(), "false");
+    assert_eq!(engine::resolve("2 >= 2").unwrap(), "true");
+    assert_eq!(engine::resolve("1 + 1 == 2").unwrap(), "true");
+    assert_eq!(engine::resolve("4 != 2 * 2").unwrap(), "false");
+    assert_eq!(engine::resolve_as::<Rational>("1/3 + 1/6 == 1/2").unwrap(), "true");
+}
+
+#[test]
+fn test_boolean_operators() {
+    assert_eq!(engine::resolve("1 < 2 and 2 < 3").unwrap(), "true");
+    assert_eq!(engine::resolve("1 > 2 or 2 > 3").unwrap(), "false");
+    assert_eq!(engine::resolve("not 1 > 2").unwrap(), "true");
+    assert_eq!(engine::resolve("true or false and false").unwrap(), "true");
+    assert_eq!(engine::resolve("(1 < 2) == (2 < 1)").unwrap(), "false");
+}
+
+#[test]
+fn test_if() {
+    assert_eq!(engine::resolve("if(2 > 1, 10, 20)").unwrap(), "10");
+    assert_eq!(engine::resolve("if(2 < 1, 10, 20) + 1").unwrap(), "21");
+    assert_eq!(engine::resolve("if(1 < 2, 1, 1 / 0)").unwrap(), "1");
+    assert_eq!(engine::resolve("if(true, if(false, 1, 2), 3)").unwrap(), "2");
+}
+
+#[test]
+fn test_thresholds_in_environment() {
+    let mut environment = Environment::<f64>::default();
+
+    environment.resolve("limit = 5").unwrap();
+    assert_eq!(environment.resolve("7 > limit"), Ok("true".to_owned()));
+    assert_eq!(environment.resolve("ans"), Ok("true".to_owned()));
+    assert_eq!(environment.resolve("big = limit > 3"), Ok("true".to_owned()));
+    assert_eq!(environment.resolve("if(big, 1, 2)"), Ok("1".to_owned()));
+    assert_eq!(environment.resolve("big + 1"), Err(Error::ExpectedNumber(Span::new(0, 3))));
+
+    environment.resolve("fact(n) = if(n <= 1, 1, n * fact(n - 1))").unwrap();
+    assert_eq!(environment.resolve("fact(10)"), Ok("3628800".to_owned()));
+}
+
+#[test]
+fn test_boolean_errors() {
+    assert_eq!(engine::resolve("(1 < 2) + 1"), Err(Error::ExpectedNumber(Span::new(1, 6))));
+    assert_eq!(engine::resolve("not 1"), Err(Error::ExpectedBoolean(Span::new(4, 5))));
+    assert_eq!(engine::resolve("if(1, 2, 3)"), Err(Error::ExpectedBoolean(Span::new(3, 4))));
+    assert_eq!(engine::resolve("if(1 < 2, 3)"), Err(Error::WrongArgumentCount("if".to_owned(), 2, Span::new(0, 12))));
+    assert_eq!(engine::resolve("true = 1"), Err(Error::InvalidAssignment(Span::new(0, 4))));
+    assert_eq!(engine::compute_with("1 < 2", &Settings::<f64>::default()), Err(Error::ExpectedNumber(Span::new(0, 5))));
+}
@@ -0,0 +1,63 @@
+use rustcalculator::engine;
+use rustcalculator::engine::complex::{Complex, ComplexContext, ComplexForm};
+use rustcalculator::engine::{Error, Settings, Span};
+
+fn resolve(expr: &str) -> Result<String, Error> {
+    engine::resolve_as::<Complex>(expr)
+}
+
+#[test]
+fn test_imaginary_unit() {
+    assert_eq!(resolve("i").unwrap(), "i");
+    assert_eq!(resolve("i ^ 2").unwrap(), "-1");
+    assert_eq!(resolve("-i").unwrap(), "-i");
+    assert_eq!(resolve("3 - 2i").unwrap(), "3 - 2i");
+    assert_eq!(resolve("sqrt(-4)").unwrap(), "2i");
+    assert_eq!(resolve("sqrt(-9) ^ 2").unwrap(), "-9");
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    assert_eq!(resolve("(1 + 2i) + (3 - i)").unwrap(), "4 + i");
+    assert_eq!(resolve("(1 + 2i) - (1 + 2i)").unwrap(), "0");
+    assert_eq!(resolve("(1 + 2i) * (3 - i)").unwrap(), "5 + 5i");
+    assert_eq!(resolve("(5 + 5i) / (1 + 2i)").unwrap(), "3 - i");
+    assert_eq!(resolve("(1 + i) ^ 4").unwrap(), "-4");
+    assert_eq!(resolve("1 / i").unwrap(), "-i");
+    assert_eq!(resolve("e ^ (pi i)").unwrap(), "-1");
+    assert_eq!(resolve("(-8) ^ (1 / 3)").unwrap(), "1 + 1.732050807568877i");
+}
+
+#[test]
+fn test_complex_functions() {
+    assert_eq!(resolve("abs(3 + 4i)").unwrap(), "5");
+    assert_eq!(resolve("arg(2i) * 2 == pi").unwrap(), "true");
+    assert_eq!(resolve("conj(3 + 4i)").unwrap(), "3 - 4i");
+    assert_eq!(resolve("re(3 + 4i)").unwrap(), "3");
+    assert_eq!(resolve("im(3 + 4i)").unwrap(), "4");
+    assert_eq!(resolve("ln(-1) == pi i").unwrap(), "true");
+    assert_eq!(resolve("log10(1000)").unwrap(), "3");
+    assert_eq!(engine::resolve("im(5) + re(5) + arg(-1) / pi").unwrap(), "6");
+}
+
+#[test]
+fn test_polar_form() {
+    let settings = Settings::new(ComplexContext { form: ComplexForm::Polar });
+
+    assert_eq!(engine::resolve_with::<Complex>("2i", &settings).unwrap(), "2e^(1.5707963267948966i)");
+    assert_eq!(engine::resolve_with::<Complex>("-i", &settings).unwrap(), "e^(-1.5707963267948966i)");
+    assert_eq!(engine::resolve_with::<Complex>("3", &settings).unwrap(), "3");
+    assert_eq!(engine::resolve_with::<Complex>("2e^(1.5707963267948966i)", &Settings::default()).unwrap(), "2i");
+}
+
+#[test]
+fn test_complex_errors() {
+    assert_eq!(resolve("i < 1"), Err(Error::Undefined(Span::new(0, 5))));
+    assert_eq!(resolve("i / 0"), Err(Error::DivisionByZero(Span::new(4, 5))));
+    assert_eq!(resolve("floor(i)"), Err(Error::Undefined(Span::new(0, 8))));
+    assert_eq!(resolve("i!"), Err(Error::Undefined(Span::new(0, 2))));
+    assert_eq!(resolve("atan(i)"), Err(Error::Undefined(Span::new(0, 7))));
+    assert_eq!(resolve("nCr(i, 1)"), Err(Error::Undefined(Span::new(0, 9))));
+    assert_eq!(resolve("i & 1"), Err(Error::NotAnInteger(Span::new(0, 5))));
+    assert_eq!(engine::resolve("sqrt(-4)"), Err(Error::Undefined(Span::new(0, 8))));
+}
@@ -0,0 +1,35 @@
+use rustcalculator::engine;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Operator, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_constant_tokens() {
+    let tokens = values(engine::tokenize("2 * π").unwrap());
+
+    let expected_tokens = vec![
+        Token::Number(2.0), Token::Operator(Operator::Mul), Token::Identifier("π".to_owned())
+    ];
+
+    assert_eq!(tokens, expected_tokens)
+}
+
+#[test]
+fn test_constant_expressions() {
+    assert_eq!(engine::resolve("pi").unwrap(), std::f64::consts::PI.to_string());
+    assert_eq!(engine::resolve("2 * pi").unwrap(), engine::resolve("tau").unwrap());
+    assert_eq!(engine::resolve("π").unwrap(), engine::resolve("pi").unwrap());
+    assert_eq!(engine::resolve("ln(e)").unwrap(), "1");
+    assert_eq!(engine::resolve("phi ^ 2 - phi").unwrap(), "1");
+    assert_eq!(engine::resolve("cos(pi)").unwrap(), "-1");
+    assert_eq!(engine::resolve_as::<Rational>("floor(pi * 100)").unwrap(), "314");
+}
+
+#[test]
+fn test_unknown_constant() {
+    assert_eq!(engine::resolve("2 * pie"), Err(Error::UnknownIdentifier("pie".to_owned(), Span::new(4, 7))));
+    assert_eq!(engine::resolve("pi 2"), Err(Error::MissingOperator(Span::new(3, 4))));
+}
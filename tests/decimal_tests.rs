@@ -0,0 +1,62 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::{Decimal, DecimalContext, Rounding};
+use rustcalculator::engine::{Error, Settings, Span};
+
+fn resolve(expression: &str, precision: u32, rounding: Rounding) -> Result<String, Error> {
+    engine::resolve_with::<Decimal>(expression, &Settings::new(DecimalContext { precision, rounding }))
+}
+
+#[test]
+fn test_exact_decimal_sum() {
+    let result = engine::resolve_as::<Decimal>("0.1 + 0.2").unwrap();
+
+    assert_eq!(result, "0.3")
+}
+
+#[test]
+fn test_decimal_literals_keep_every_digit() {
+    let result = engine::resolve_as::<Decimal>("1234567890.123456789 - 1234567890").unwrap();
+
+    assert_eq!(result, "0.123456789")
+}
+
+#[test]
+fn test_decimal_precision() {
+    assert_eq!(resolve("1 / 3", 4, Rounding::HalfEven).unwrap(), "0.3333");
+    assert_eq!(resolve("2 / 3", 4, Rounding::HalfEven).unwrap(), "0.6667");
+    assert_eq!(resolve("10 / 4", 0, Rounding::Down).unwrap(), "2");
+}
+
+#[test]
+fn test_decimal_rounding_modes() {
+    assert_eq!(resolve("0.125 * 1", 2, Rounding::HalfEven).unwrap(), "0.12");
+    assert_eq!(resolve("0.125 * 1", 2, Rounding::HalfUp).unwrap(), "0.13");
+    assert_eq!(resolve("0.125 * 1", 2, Rounding::HalfDown).unwrap(), "0.12");
+    assert_eq!(resolve("0.121 * 1", 2, Rounding::Up).unwrap(), "0.13");
+    assert_eq!(resolve("0.129 * 1", 2, Rounding::Down).unwrap(), "0.12");
+    assert_eq!(resolve("(0 - 0.121) * 1", 2, Rounding::Ceiling).unwrap(), "-0.12");
+    assert_eq!(resolve("(0 - 0.121) * 1", 2, Rounding::Floor).unwrap(), "-0.13");
+}
+
+#[test]
+fn test_decimal_division_by_zero() {
+    assert_eq!(engine::resolve_as::<Decimal>("1 / 0.0"), Err(Error::DivisionByZero(Span::new(4, 7))));
+}
+
+#[test]
+fn test_decimal_overflow() {
+    let huge = "9".repeat(28);
+
+    assert_eq!(engine::resolve_as::<Decimal>(&format!("{0} * {0}", huge)), Err(Error::Overflow(Span::new(0, 59))));
+}
+
+#[test]
+fn test_decimal_scientific_literals() {
+    assert_eq!(engine::resolve_as::<Decimal>("1e30"), Err(Error::Overflow(Span::new(0, 4))));
+    assert_eq!(engine::resolve_as::<Decimal>("1e-30").unwrap(), "0");
+    assert_eq!(engine::resolve_as::<Decimal>("1e-30 * 1e30 + 1"), Err(Error::Overflow(Span::new(8, 12))));
+    assert_eq!(engine::resolve_as::<Decimal>("1.5e-12").unwrap(), "0.0000000000015");
+    assert_eq!(engine::resolve_as::<Decimal>("1e28").unwrap(), "10000000000000000000000000000");
+    assert_eq!(resolve("2.5e-3", 2, Rounding::HalfUp).unwrap(), "0");
+    assert_eq!(resolve("6e-3", 2, Rounding::HalfUp).unwrap(), "0.01");
+}
@@ -0,0 +1,44 @@
+use rustcalculator::engine;
+use rustcalculator::engine::{Operator, Span, Spanned, Token};
+
+#[test]
+fn test_engine_steps() {
+    let tokens = engine::tokenize::<f64>("(1 + 2) * 4").unwrap();
+    let queue = engine::to_rpn(tokens);
+
+    let expected_queue: Vec<Token> = vec![
+        Token::Number(1.0), Token::Number(2.0), Token::Operator(Operator::Add),
+        Token::Number(4.0), Token::Operator(Operator::Mul)
+    ];
+
+    assert_eq!(queue.iter().map(|token| token.value.clone()).collect::<Vec<Token>>(), expected_queue);
+    assert_eq!(engine::evaluate(queue), Ok(12.0));
+}
+
+#[test]
+fn test_engine_resolve() {
+    let result = engine::resolve("10 / 4").unwrap();
+
+    assert_eq!(result, "2.5")
+}
+
+#[test]
+fn test_format_result() {
+    assert_eq!(engine::format_result(&15.0), "15");
+    assert_eq!(engine::format_result(&0.25), "0.25");
+}
+
+#[test]
+fn test_token_spans() {
+    let tokens = engine::tokenize::<f64>("(12.5 +3)").unwrap();
+
+    let expected_tokens = vec![
+        Spanned::new(Token::Bracket('('), Span::new(0, 1)),
+        Spanned::new(Token::Number(12.5), Span::new(1, 5)),
+        Spanned::new(Token::Operator(Operator::Add), Span::new(6, 7)),
+        Spanned::new(Token::Number(3.0), Span::new(7, 8)),
+        Spanned::new(Token::Bracket(')'), Span::new(8, 9)),
+    ];
+
+    assert_eq!(tokens, expected_tokens);
+}
@@ -0,0 +1,64 @@
+use rustcalculator::engine;
+use rustcalculator::engine::{Error, Operator, Span};
+
+#[test]
+fn test_trailing_operator() {
+    assert_eq!(engine::resolve("2+"), Err(Error::TrailingOperator(Operator::Add, Span::new(1, 2))));
+    assert_eq!(engine::resolve("(2*)"), Err(Error::TrailingOperator(Operator::Mul, Span::new(2, 3))));
+}
+
+#[test]
+fn test_missing_operand() {
+    assert_eq!(engine::resolve("*3"), Err(Error::MissingOperand(Operator::Mul, Span::new(0, 1))));
+    assert_eq!(engine::resolve("2+/3"), Err(Error::MissingOperand(Operator::Div, Span::new(2, 3))));
+}
+
+#[test]
+fn test_mismatched_parens() {
+    assert_eq!(engine::resolve("1)"), Err(Error::MismatchedParens(Span::new(1, 2))));
+    assert_eq!(engine::resolve("(1+(2)"), Err(Error::MismatchedParens(Span::new(0, 1))));
+}
+
+#[test]
+fn test_empty_expression() {
+    assert_eq!(engine::resolve(" "), Err(Error::EmptyExpression(Span::new(0, 1))));
+    assert_eq!(engine::resolve("2*()"), Err(Error::EmptyExpression(Span::new(2, 4))));
+}
+
+#[test]
+fn test_missing_operator() {
+    assert_eq!(engine::resolve("2 34"), Err(Error::MissingOperator(Span::new(2, 4))));
+}
+
+#[test]
+fn test_bad_token() {
+    assert_eq!(engine::resolve("1 + €"), Err(Error::BadToken('€', Span::new(4, 7))));
+}
+
+#[test]
+fn test_division_by_zero() {
+    assert_eq!(engine::resolve("1/(2-2)"), Err(Error::DivisionByZero(Span::new(3, 6))));
+}
+
+#[test]
+fn test_overflow() {
+    let huge = "9".repeat(200);
+    let expression = format!("1 + {0}*{0}", huge);
+
+    assert_eq!(engine::resolve(&expression), Err(Error::Overflow(Span::new(4, expression.len()))));
+}
+
+#[test]
+fn test_error_char_range() {
+    let expression = "π + 1 +";
+    let error = Error::TrailingOperator(Operator::Add, Span::new(7, 8));
+
+    assert_eq!(error.span().char_range(expression), 6..7);
+}
+
+#[test]
+fn test_evaluate_reports_missing_operand() {
+    let token = engine::Spanned::new(engine::Token::<f64>::Operator(Operator::Add), Span::new(0, 1));
+
+    assert_eq!(engine::evaluate(vec![token]), Err(Error::MissingOperand(Operator::Add, Span::new(0, 1))));
+}
@@ -0,0 +1,68 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Operator, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_factorial_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("2 ^ 3!").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(2.0), Token::Number(3.0),
+        Token::Operator(Operator::Factorial), Token::Operator(Operator::Pow)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_factorial_expressions() {
+    assert_eq!(engine::resolve("5!").unwrap(), "120");
+    assert_eq!(engine::resolve("0!").unwrap(), "1");
+    assert_eq!(engine::resolve("-3!").unwrap(), "-6");
+    assert_eq!(engine::resolve("3!!").unwrap(), "720");
+    assert_eq!(engine::resolve("2 ^ 3!").unwrap(), "64");
+    assert_eq!(engine::resolve("(1 + 2)! * 2").unwrap(), "12");
+    assert_eq!(engine::resolve("round(0.5! ^ 2 * 1000)").unwrap(), "785");
+}
+
+#[test]
+fn test_factorial_stays_exact() {
+    assert_eq!(engine::resolve_as::<Integer>("25!").unwrap(), "15511210043330985984000000");
+    assert_eq!(engine::resolve_as::<Integer>("100! / 98!").unwrap(), "9900");
+    assert_eq!(engine::resolve_as::<Rational>("30! / 29!").unwrap(), "30");
+    assert_eq!(engine::resolve_as::<Decimal>("20!").unwrap(), "2432902008176640000");
+}
+
+#[test]
+fn test_combinatorics() {
+    assert_eq!(engine::resolve("nCr(5, 2)").unwrap(), "10");
+    assert_eq!(engine::resolve("nPr(5, 2)").unwrap(), "20");
+    assert_eq!(engine::resolve("nCr(3, 5)").unwrap(), "0");
+    assert_eq!(engine::resolve("gamma(5)").unwrap(), "24");
+    assert_eq!(engine::resolve_as::<Integer>("nCr(100, 50)").unwrap(), "100891344545564193334812497256");
+    assert_eq!(engine::resolve("nCr(20000, 1)").unwrap(), "20000");
+    assert_eq!(engine::resolve("nPr(20000, 2)").unwrap(), "399980000");
+    let binomial = engine::resolve("nCr(1000, 500)").unwrap();
+    assert!(binomial.starts_with("2.70288240945436") && binomial.ends_with("e299"));
+}
+
+#[test]
+fn test_factorial_errors() {
+    assert_eq!(engine::resolve("171!"), Err(Error::Overflow(Span::new(0, 4))));
+    assert_eq!(engine::resolve_as::<Decimal>("30!"), Err(Error::Overflow(Span::new(0, 3))));
+    assert_eq!(engine::resolve_as::<Integer>("100000!"), Err(Error::Overflow(Span::new(0, 7))));
+    assert_eq!(engine::resolve("(-2)!"), Err(Error::Undefined(Span::new(1, 5))));
+    assert_eq!(engine::resolve("nCr(5, 1.5)"), Err(Error::Undefined(Span::new(0, 11))));
+    assert_eq!(engine::resolve("gamma(0)"), Err(Error::Undefined(Span::new(0, 8))));
+    assert_eq!(engine::resolve("gamma(10 ^ 300)"), Err(Error::Overflow(Span::new(0, 15))));
+    assert_eq!(engine::resolve("nCr(1e30, 2)"), Err(Error::Overflow(Span::new(0, 12))));
+    assert_eq!(engine::resolve("nPr(1e30, 1)"), Err(Error::Overflow(Span::new(0, 12))));
+    assert_eq!(engine::resolve_as::<Rational>("nPr(20000, 10001)"), Err(Error::Overflow(Span::new(0, 17))));
+    assert_eq!(engine::resolve_as::<Rational>("nCr(30000, 15000)"), Err(Error::Overflow(Span::new(0, 17))));
+}
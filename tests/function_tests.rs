@@ -0,0 +1,70 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Operator, Span, Token};
+
+mod common;
+
+use common::values;
+
+// EXPRESSION: max(1, 2 + 3)
+// TOKEN | ACTION                        | QUEUE         | STACK
+// max   | Add to stack                  |               | max
+// (     | Add to stack                  |               | max (
+// 1     | Add to queue                  | 1             | max (
+// ,     | Pop stack up to (, count arg  | 1             | max (
+// 2     | Add to queue                  | 1 2           | max (
+// +     | Add to stack                  | 1 2           | max ( +
+// 3     | Add to queue                  | 1 2 3         | max ( +
+// )     | Pop stack up to (, emit call  | 1 2 3 + max/2 |
+#[test]
+fn test_function_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("max(1, 2 + 3)").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(1.0), Token::Number(2.0), Token::Number(3.0),
+        Token::Operator(Operator::Add), Token::Call("max".to_owned(), 2)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_function_expressions() {
+    assert_eq!(engine::resolve("sqrt(16)").unwrap(), "4");
+    assert_eq!(engine::resolve("abs(-3) + 1").unwrap(), "4");
+    assert_eq!(engine::resolve("2 * sqrt (9)").unwrap(), "6");
+    assert_eq!(engine::resolve("-sqrt(4)").unwrap(), "-2");
+    assert_eq!(engine::resolve("sqrt(sqrt(16))").unwrap(), "2");
+    assert_eq!(engine::resolve("sin(0) + cos(0)").unwrap(), "1");
+    assert_eq!(engine::resolve("atan2(1, 1) * 4").unwrap(), engine::resolve("acos(-1)").unwrap());
+    assert_eq!(engine::resolve("ln(exp(2))").unwrap(), "2");
+    assert_eq!(engine::resolve("log10(1000)").unwrap(), "3");
+    assert_eq!(engine::resolve("log(100)").unwrap(), "2");
+    assert_eq!(engine::resolve("log(8, 2)").unwrap(), "3");
+    assert_eq!(engine::resolve("floor(-2.5) + ceil(2.1) + round(2.5)").unwrap(), "3");
+    assert_eq!(engine::resolve("min(4, 2, 8) + max(4, 2, 8)").unwrap(), "10");
+}
+
+#[test]
+fn test_function_exact_backends() {
+    assert_eq!(engine::resolve_as::<Rational>("abs(-1/3) + max(1/6, 1/7)").unwrap(), "1/2");
+    assert_eq!(engine::resolve_as::<Rational>("floor(7/2)").unwrap(), "3");
+    assert_eq!(engine::resolve_as::<Decimal>("round(2.345 * 100) / 100").unwrap(), "2.35");
+    assert_eq!(engine::resolve_as::<Integer>("max(2 ^ 70, 3) - 2 ^ 70").unwrap(), "0");
+    assert_eq!(engine::resolve_as::<Integer>("sqrt(2 ^ 10)").unwrap(), "32");
+}
+
+#[test]
+fn test_function_errors() {
+    assert_eq!(engine::resolve("foo(1)"), Err(Error::UnknownFunction("foo".to_owned(), Span::new(0, 6))));
+    assert_eq!(engine::resolve("foo + 1"), Err(Error::UnknownIdentifier("foo".to_owned(), Span::new(0, 3))));
+    assert_eq!(engine::resolve("1 + atan2(1)"), Err(Error::WrongArgumentCount("atan2".to_owned(), 1, Span::new(4, 12))));
+    assert_eq!(engine::resolve("sqrt()"), Err(Error::WrongArgumentCount("sqrt".to_owned(), 0, Span::new(0, 6))));
+    assert_eq!(engine::resolve("sqrt(-1)"), Err(Error::Undefined(Span::new(0, 8))));
+    assert_eq!(engine::resolve("ln(0)"), Err(Error::Undefined(Span::new(0, 5))));
+    assert_eq!(engine::resolve("max(1,)"), Err(Error::EmptyExpression(Span::new(5, 7))));
+    assert_eq!(engine::resolve("1, 2"), Err(Error::BadToken(',', Span::new(1, 2))));
+    assert_eq!(engine::resolve("sqrt(4) 2"), Err(Error::MissingOperator(Span::new(8, 9))));
+}
@@ -0,0 +1,66 @@
+use rustcalculator::engine;
+use rustcalculator::engine::{Environment, Error, ImplicitMultiplication, Operator, Settings, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_implicit_multiplication_tokens() {
+    let tokens = engine::tokenize::<f64>("2(3)").unwrap();
+
+    assert_eq!(tokens[1].value, Token::Operator(Operator::Mul));
+    assert_eq!(tokens[1].span, Span::new(1, 1));
+
+    let tight = Settings::<f64> { implicit_multiplication: ImplicitMultiplication::Tight, ..Default::default() };
+    let tokens = values(engine::tokenize_with("2pi", &tight).unwrap());
+
+    assert_eq!(tokens, vec![
+        Token::Number(2.0), Token::Operator(Operator::ImplicitMul), Token::Identifier("pi".to_owned())
+    ]);
+}
+
+#[test]
+fn test_implicit_multiplication_expressions() {
+    assert_eq!(engine::resolve("2(3+4)").unwrap(), "14");
+    assert_eq!(engine::resolve("(1+2)(3+4)").unwrap(), "21");
+    assert_eq!(engine::resolve("2pi").unwrap(), engine::resolve("tau").unwrap());
+    assert_eq!(engine::resolve("2 sqrt(9)").unwrap(), "6");
+    assert_eq!(engine::resolve("-2(3)").unwrap(), "-6");
+
+    let mut environment = Environment::<f64>::default();
+    environment.resolve("x = 3").unwrap();
+    assert_eq!(environment.resolve("2x^2").unwrap(), "18");
+    assert_eq!(environment.resolve("(x)(x)x").unwrap(), "27");
+}
+
+#[test]
+fn test_implicit_multiplication_precedence() {
+    let mut environment = Environment::<f64>::default();
+    environment.resolve("x = 4").unwrap();
+    assert_eq!(environment.resolve("1/2x").unwrap(), "2");
+
+    environment.settings.implicit_multiplication = ImplicitMultiplication::Tight;
+    assert_eq!(environment.resolve("1/2x").unwrap(), "0.125");
+    assert_eq!(environment.resolve("1/2*x").unwrap(), "2");
+    assert_eq!(environment.resolve("2^2x").unwrap(), "16");
+}
+
+#[test]
+fn test_implicit_multiplication_limits() {
+    assert_eq!(engine::resolve("2 3"), Err(Error::MissingOperator(Span::new(2, 3))));
+    assert_eq!(engine::resolve("(2)3"), Err(Error::MissingOperator(Span::new(3, 4))));
+}
+
+#[test]
+fn test_value_followed_by_bracket() {
+    assert_eq!(engine::resolve("pi(2)").unwrap(), engine::resolve("2pi").unwrap());
+
+    let mut environment = Environment::<f64>::default();
+    environment.resolve("x = 3").unwrap();
+    assert_eq!(environment.resolve("x (2)").unwrap(), "6");
+    assert_eq!(environment.resolve("ans(2)").unwrap(), "12");
+    assert_eq!(environment.resolve("x(y) = y + 1").unwrap(), "x(y) = y + 1");
+    assert_eq!(environment.resolve("x(2)").unwrap(), "3");
+    assert_eq!(engine::resolve("foo(2)"), Err(Error::UnknownFunction("foo".to_owned(), Span::new(0, 6))));
+}
@@ -0,0 +1,67 @@
+use rustcalculator::engine;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::{Error, Settings, Span};
+
+#[test]
+fn test_integer_stays_exact_past_float_range() {
+    let expression = vec!["2"; 100].join("*");
+    let result = engine::resolve_as::<Integer>(&expression).unwrap();
+
+    assert_eq!(result, "1267650600228229401496703205376")
+}
+
+#[test]
+fn test_integer_exact_division() {
+    let result = engine::resolve_as::<Integer>("123456789123456789123456789 / 3").unwrap();
+
+    assert_eq!(result, "41152263041152263041152263")
+}
+
+#[test]
+fn test_integer_promotes_on_remainder() {
+    assert_eq!(engine::resolve_as::<Integer>("7 / 2").unwrap(), "3.5");
+    assert_eq!(engine::resolve_as::<Integer>("7 / 2 * 2").unwrap(), "7");
+    assert_eq!(engine::resolve_as::<Integer>("1.5 + 1").unwrap(), "2.5");
+}
+
+#[test]
+fn test_integer_literal_with_zero_fraction() {
+    let tokens = engine::tokenize::<Integer>("3.00").unwrap();
+
+    assert_eq!(tokens[0].value, engine::Token::Number(Integer::Exact(3.into())))
+}
+
+#[test]
+fn test_integer_division_by_zero() {
+    assert_eq!(engine::resolve_as::<Integer>("5 / (2 - 2)"), Err(Error::DivisionByZero(Span::new(5, 10))))
+}
+
+#[test]
+fn test_integer_promotion_overflow() {
+    let huge = "9".repeat(400);
+
+    assert_eq!(engine::resolve_as::<Integer>(&format!("{} / 7", huge)), Err(Error::Overflow(Span::new(0, 404))))
+}
+
+#[test]
+fn test_integer_rejects_lone_point() {
+    assert_eq!(engine::resolve_as::<Integer>("."), Err(Error::InvalidNumber(Span::new(0, 1))));
+}
+
+#[test]
+fn test_integer_powers_of_unit_bases() {
+    assert_eq!(engine::resolve_as::<Integer>("1^5000000000").unwrap(), "1");
+    assert_eq!(engine::resolve_as::<Integer>("(-1)^5000000001").unwrap(), "-1");
+    assert_eq!(engine::resolve_as::<Integer>("(-1)^5000000000").unwrap(), "1");
+    assert_eq!(engine::resolve_as::<Integer>("0^5000000000").unwrap(), "0");
+    assert_eq!(engine::resolve_as::<Integer>("0^0").unwrap(), "1");
+}
+
+#[test]
+fn test_integer_large_floats_stay_approximate() {
+    assert_eq!(engine::resolve_as::<Integer>("sqrt(16)").unwrap(), "4");
+    assert_eq!(engine::resolve_as::<Integer>("sqrt(2 ^ 100)").unwrap(), "1125899906842624");
+    assert_eq!(engine::resolve_as::<Integer>("sqrt(2 ^ 120)").unwrap(), "1.152921504606847e18");
+    assert_eq!(engine::resolve_as::<Integer>("exp(100)").unwrap(), "2.6881171418161356e43");
+    assert!(matches!(engine::compute_with("exp(100)", &Settings::<Integer>::default()), Ok(Integer::Float(_))));
+}
@@ -0,0 +1,66 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Notation, Settings, Span, Token};
+
+#[test]
+fn test_scientific_literals() {
+    let tokens = engine::tokenize::<f64>("1.5e-3 + 6.02E23").unwrap();
+
+    assert_eq!(tokens[0].value, Token::Number(0.0015));
+    assert_eq!(tokens[0].span, Span::new(0, 6));
+    assert_eq!(tokens[2].value, Token::Number(6.02e23));
+
+    assert_eq!(engine::resolve("1e3 + 1").unwrap(), "1001");
+    assert_eq!(engine::resolve("2.5e+2").unwrap(), "250");
+    assert_eq!(engine::resolve_as::<Decimal>("1.5e-3 * 2").unwrap(), "0.003");
+    assert_eq!(engine::resolve_as::<Rational>("1e-3").unwrap(), "1/1000");
+    assert_eq!(engine::resolve_as::<Integer>("2e30 + 1").unwrap(), "2000000000000000000000000000001");
+}
+
+#[test]
+fn test_exponent_needs_digits() {
+    assert_eq!(engine::resolve("2e").unwrap(), engine::resolve("2 * e").unwrap());
+    assert_eq!(engine::resolve("2exp(0)").unwrap(), "2");
+    assert_eq!(engine::resolve("1e99999999999"), Err(Error::InvalidNumber(Span::new(0, 13))));
+    assert_eq!(engine::resolve("1e400"), Err(Error::Overflow(Span::new(0, 5))));
+}
+
+#[test]
+fn test_si_suffixes() {
+    let settings = Settings::<f64> { si_suffixes: true, ..Default::default() };
+
+    assert_eq!(engine::resolve_with("4.7k", &settings).unwrap(), "4700");
+    assert_eq!(engine::resolve_with("220n * 1M", &settings).unwrap(), "0.22");
+    assert_eq!(engine::resolve_with("2pi", &settings).unwrap(), engine::resolve("tau").unwrap());
+    let exact = Settings::<Rational> { si_suffixes: true, ..Default::default() };
+    assert_eq!(engine::resolve_with("3m", &exact).unwrap(), "3/1000");
+    assert_eq!(engine::resolve("4.7k"), Err(Error::UnknownIdentifier("k".to_owned(), Span::new(3, 4))));
+}
+
+#[test]
+fn test_result_notation() {
+    let scientific = Settings::<f64> { notation: Notation::Scientific, ..Default::default() };
+    let engineering = Settings::<f64> { notation: Notation::Engineering, ..Default::default() };
+
+    assert_eq!(engine::resolve_with("6.02e23", &scientific).unwrap(), "6.02e23");
+    assert_eq!(engine::resolve_with("-1.5e-13", &scientific).unwrap(), "-1.5e-13");
+    assert_eq!(engine::resolve_with("6.02e23", &engineering).unwrap(), "602e21");
+    assert_eq!(engine::resolve_with("2e13", &engineering).unwrap(), "20e12");
+    assert_eq!(engine::resolve_with("1234", &engineering).unwrap(), "1234");
+    assert_eq!(engine::resolve_with("0", &scientific).unwrap(), "0");
+
+    let settings = Settings::<Integer> { notation: Notation::Scientific, ..Default::default() };
+    assert_eq!(engine::resolve_with("2 ^ 100", &settings).unwrap(), "1.2676506002282294e30");
+}
+
+#[test]
+fn test_default_notation() {
+    assert_eq!(engine::resolve("1e300").unwrap(), "1e300");
+    assert_eq!(engine::resolve("6.02E23").unwrap(), "6.02e23");
+    assert_eq!(engine::resolve("30!").unwrap(), "2.6525285981219107e32");
+    assert_eq!(engine::resolve("123456789").unwrap(), "123456789");
+    assert_eq!(engine::resolve_as::<Integer>("2 ^ 100").unwrap(), "1267650600228229401496703205376");
+    assert_eq!(engine::resolve_as::<Rational>("1e30").unwrap(), "1000000000000000000000000000000");
+}
@@ -0,0 +1,41 @@
+use rustcalculator::engine;
+use rustcalculator::engine::{Error, Span};
+
+#[test]
+fn test_default_backend_is_f64() {
+    let result = engine::resolve("16777217 + 1").unwrap();
+
+    assert_eq!(result, "16777218")
+}
+
+#[test]
+fn test_f32_backend() {
+    let result = engine::resolve_as::<f32>("16777217 + 1").unwrap();
+
+    assert_eq!(result, "16777216")
+}
+
+#[test]
+fn test_literals_are_read_as_written() {
+    let result = engine::resolve("0.1 * 3").unwrap();
+
+    assert_eq!(result, "0.30000000000000004")
+}
+
+#[test]
+fn test_leading_and_trailing_point() {
+    assert_eq!(engine::resolve(".5 + 2.").unwrap(), "2.5")
+}
+
+#[test]
+fn test_invalid_number() {
+    assert_eq!(engine::resolve("1 + 1.2.3"), Err(Error::InvalidNumber(Span::new(4, 9))));
+    assert_eq!(engine::resolve("."), Err(Error::InvalidNumber(Span::new(0, 1))));
+}
+
+#[test]
+fn test_negative_zero_is_printed_as_zero() {
+    assert_eq!(engine::resolve("-0").unwrap(), "0");
+    assert_eq!(engine::resolve("0 * -1").unwrap(), "0");
+    assert_eq!(engine::resolve_as::<f32>("-0").unwrap(), "0");
+}
@@ -0,0 +1,58 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Operator, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_percent_or_remainder() {
+    let percent = values(engine::tokenize("10% - 3").unwrap());
+    assert_eq!(percent[1], Token::Operator(Operator::Percent));
+
+    let remainder = values(engine::tokenize("10 % -3").unwrap());
+    assert_eq!(remainder[1], Token::Operator(Operator::Rem));
+
+    let remainder = values(engine::tokenize("10 % (3)").unwrap());
+    assert_eq!(remainder[1], Token::Operator(Operator::Rem));
+}
+
+// EXPRESSION: 200 + 10%
+// TOKEN | ACTION                        | QUEUE         | STACK
+// 200   | Add to queue                  | 200           |
+// +     | Add to stack                  | 200           | +
+// 10    | Add to queue                  | 200 10        | +
+// %     | Postfix, add to queue         | 200 10 %      | +
+// end   | Pop entire stack to output    | 200 10 % +    |
+#[test]
+fn test_percent_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("200 + 10%").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(200.0), Token::Number(10.0),
+        Token::Operator(Operator::Percent), Token::Operator(Operator::Add)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_percent_expressions() {
+    assert_eq!(engine::resolve("200 + 10%").unwrap(), "220");
+    assert_eq!(engine::resolve("200 - 10%").unwrap(), "180");
+    assert_eq!(engine::resolve("50 * 10%").unwrap(), "5");
+    assert_eq!(engine::resolve("50 / 10%").unwrap(), "500");
+    assert_eq!(engine::resolve("25%").unwrap(), "0.25");
+    assert_eq!(engine::resolve("(100 + 50%) * 2").unwrap(), "300");
+    assert_eq!(engine::resolve("7 % 3").unwrap(), "1");
+    assert_eq!(engine::resolve_as::<Decimal>("19.99 + 8%").unwrap(), "21.5892");
+    assert_eq!(engine::resolve_as::<Rational>("1/3 - 50%").unwrap(), "1/6");
+}
+
+#[test]
+fn test_percent_errors() {
+    assert_eq!(engine::resolve("%"), Err(Error::MissingOperand(Operator::Percent, Span::new(0, 1))));
+    assert_eq!(engine::resolve("2 + %"), Err(Error::MissingOperand(Operator::Percent, Span::new(4, 5))));
+}
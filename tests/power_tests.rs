@@ -0,0 +1,73 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Associativity, Error, Operator, Span, Spanned, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_power_associativity() {
+    assert_eq!(Operator::Pow.associativity(), Associativity::Right);
+    assert_eq!(Operator::Sub.associativity(), Associativity::Left);
+}
+
+// EXPRESSION: 2 ^ 3 ^ 2
+// TOKEN | ACTION                        | QUEUE         | STACK
+// 2     | Add to queue                  | 2             |
+// ^     | Add to stack                  | 2             | ^
+// 3     | Add to queue                  | 2 3           | ^
+// ^     | Right associative, add        | 2 3           | ^ ^
+// 2     | Add to queue                  | 2 3 2         | ^ ^
+// end   | Pop entire stack to output    | 2 3 2 ^ ^     |
+#[test]
+fn test_power_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("2 ^ 3 ^ 2").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(2.0), Token::Number(3.0), Token::Number(2.0),
+        Token::Operator(Operator::Pow), Token::Operator(Operator::Pow)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_power_expressions() {
+    assert_eq!(engine::resolve("2 ^ 3 ^ 2").unwrap(), "512");
+    assert_eq!(engine::resolve("2 ** 10").unwrap(), "1024");
+    assert_eq!(engine::resolve("3 * 2 ^ 2").unwrap(), "12");
+    assert_eq!(engine::resolve("4 ^ 0.5").unwrap(), "2");
+    assert_eq!(engine::resolve("2 ^ -1").unwrap(), "0.5");
+}
+
+#[test]
+fn test_power_binds_tighter_than_negation() {
+    assert_eq!(engine::resolve("-2 ^ 2").unwrap(), "-4");
+    assert_eq!(engine::resolve("(-2) ^ 2").unwrap(), "4");
+}
+
+#[test]
+fn test_power_alias_span() {
+    let tokens = engine::tokenize::<f64>("2**3").unwrap();
+
+    assert_eq!(tokens[1], Spanned::new(Token::Operator(Operator::Pow), Span::new(1, 3)))
+}
+
+#[test]
+fn test_power_on_exact_backends() {
+    assert_eq!(engine::resolve_as::<Rational>("(2/3) ^ -2").unwrap(), "9/4");
+    assert_eq!(engine::resolve_as::<Decimal>("1.1 ^ 2").unwrap(), "1.21");
+    assert_eq!(engine::resolve_as::<Integer>("2 ^ 100").unwrap(), "1267650600228229401496703205376");
+    assert_eq!(engine::resolve_as::<Integer>("2 ^ -2").unwrap(), "0.25");
+}
+
+#[test]
+fn test_power_errors() {
+    assert_eq!(engine::resolve("0 ^ -1"), Err(Error::DivisionByZero(Span::new(0, 6))));
+    assert_eq!(engine::resolve("(-8) ^ 0.5"), Err(Error::Undefined(Span::new(1, 10))));
+    assert_eq!(engine::resolve("10 ^ 400"), Err(Error::Overflow(Span::new(0, 8))));
+    assert_eq!(engine::resolve_as::<Integer>("10 ^ 1000000"), Err(Error::Overflow(Span::new(0, 12))));
+}
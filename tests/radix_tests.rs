@@ -0,0 +1,37 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::{Environment, Error, Span, Token};
+
+#[test]
+fn test_radix_literals() {
+    let tokens = engine::tokenize::<f64>("0xFF + 0b1010").unwrap();
+
+    assert_eq!(tokens[0].value, Token::Number(255.0));
+    assert_eq!(tokens[0].span, Span::new(0, 4));
+    assert_eq!(tokens[2].value, Token::Number(10.0));
+    assert_eq!(tokens[2].span, Span::new(7, 13));
+
+    assert_eq!(engine::resolve("0o17").unwrap(), "15");
+    assert_eq!(engine::resolve("0Xff - 0B11 * 0O10").unwrap(), "231");
+    assert_eq!(engine::resolve("0xFF_FF").unwrap(), "65535");
+    assert_eq!(engine::resolve_as::<Integer>("0xFFFF_FFFF_FFFF_FFFF_FFFF + 1").unwrap(), "1208925819614629174706176");
+    assert_eq!(engine::resolve_as::<Decimal>("0x10 / 0b100").unwrap(), "4");
+}
+
+#[test]
+fn test_digit_separators() {
+    assert_eq!(engine::resolve("1_000_000 + 1").unwrap(), "1000001");
+    assert_eq!(engine::resolve("1_000.000_5").unwrap(), "1000.0005");
+    assert_eq!(engine::resolve_as::<Integer>("1_000_000_000_000_000_000 * 10").unwrap(), "10000000000000000000");
+}
+
+#[test]
+fn test_radix_prefix_needs_digits() {
+    let mut environment = Environment::<f64>::default();
+    environment.resolve("xy = 3").unwrap();
+
+    assert_eq!(environment.resolve("0xy").unwrap(), "0");
+    assert_eq!(engine::resolve("0b102"), Err(Error::InvalidNumber(Span::new(0, 5))));
+    assert_eq!(engine::resolve("0xFG"), Err(Error::InvalidNumber(Span::new(0, 4))));
+}
@@ -0,0 +1,54 @@
+use rustcalculator::engine;
+use rustcalculator::engine::rational::{FractionStyle, Rational, RationalContext};
+use rustcalculator::engine::{Error, Settings, Span};
+
+fn resolve(expression: &str, style: FractionStyle) -> String {
+    let context = RationalContext { style, ..Default::default() };
+
+    engine::resolve_with::<Rational>(expression, &Settings::new(context)).unwrap()
+}
+
+#[test]
+fn test_rational_sum() {
+    assert_eq!(resolve("1/3 + 1/6", FractionStyle::Fraction), "1/2")
+}
+
+#[test]
+fn test_rational_literals_are_exact() {
+    assert_eq!(resolve("0.1 + 0.2", FractionStyle::Fraction), "3/10");
+    assert_eq!(resolve("0.1 + 0.2", FractionStyle::Decimal), "0.3");
+}
+
+#[test]
+fn test_rational_mixed_number() {
+    assert_eq!(resolve("3/2", FractionStyle::Mixed), "1 1/2");
+    assert_eq!(resolve("1/2", FractionStyle::Mixed), "1/2");
+    assert_eq!(resolve("1 - 5/2", FractionStyle::Mixed), "-1 1/2");
+    assert_eq!(resolve("8/4", FractionStyle::Mixed), "2");
+}
+
+#[test]
+fn test_rational_decimal() {
+    assert_eq!(resolve("2/3", FractionStyle::Decimal), "0.6666666667");
+    assert_eq!(resolve("1 - 5/4", FractionStyle::Decimal), "-0.25");
+    assert_eq!(resolve("1/3 * 3", FractionStyle::Decimal), "1");
+}
+
+#[test]
+fn test_rational_decimal_places() {
+    let context = RationalContext { style: FractionStyle::Decimal, decimal_places: 3 };
+
+    assert_eq!(engine::resolve_with::<Rational>("1/7", &Settings::new(context)).unwrap(), "0.143")
+}
+
+#[test]
+fn test_rational_stays_exact_with_large_values() {
+    let huge = "9".repeat(40);
+
+    assert_eq!(resolve(&format!("({0} + 1) / {0}", huge), FractionStyle::Fraction), format!("1{}/{}", "0".repeat(40), huge))
+}
+
+#[test]
+fn test_rational_division_by_zero() {
+    assert_eq!(engine::resolve_as::<Rational>("1 / (1/2 - 0.5)"), Err(Error::DivisionByZero(Span::new(5, 14))))
+}
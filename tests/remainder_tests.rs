@@ -0,0 +1,68 @@
+use rustcalculator::engine;
+use rustcalculator::engine::decimal::Decimal;
+use rustcalculator::engine::integer::Integer;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Error, Number, Operator, Remainder, Settings, Span, Spanned, Token};
+
+fn floored<N: Number>(expression: &str) -> String {
+    let settings = Settings::<N> { remainder: Remainder::Floored, ..Default::default() };
+
+    engine::resolve_with(expression, &settings).unwrap()
+}
+
+#[test]
+fn test_remainder_tokens() {
+    let tokens: Vec<Token> = engine::tokenize("7 % 3 // 2").unwrap().into_iter().map(|token| token.value).collect();
+
+    let expected_tokens = vec![
+        Token::Number(7.0), Token::Operator(Operator::Rem), Token::Number(3.0),
+        Token::Operator(Operator::FloorDiv), Token::Number(2.0)
+    ];
+
+    assert_eq!(tokens, expected_tokens)
+}
+
+#[test]
+fn test_floor_division_span() {
+    let tokens = engine::tokenize::<f64>("7//2").unwrap();
+
+    assert_eq!(tokens[1], Spanned::new(Token::Operator(Operator::FloorDiv), Span::new(1, 3)))
+}
+
+#[test]
+fn test_truncated_remainder() {
+    assert_eq!(engine::resolve("7 % 3").unwrap(), "1");
+    assert_eq!(engine::resolve("-7 % 3").unwrap(), "-1");
+    assert_eq!(engine::resolve("7 % -3").unwrap(), "1");
+    assert_eq!(engine::resolve("5.5 % 2").unwrap(), "1.5");
+}
+
+#[test]
+fn test_floored_remainder() {
+    assert_eq!(floored::<f64>("-7 % 3"), "2");
+    assert_eq!(floored::<f64>("7 % -3"), "-2");
+    assert_eq!(floored::<Integer>("-7 % 3"), "2");
+    assert_eq!(floored::<Decimal>("-7.5 % 2"), "0.5");
+    assert_eq!(floored::<Rational>("-7/2 % 2"), "1/2");
+}
+
+#[test]
+fn test_floor_division() {
+    assert_eq!(engine::resolve("7 // 2").unwrap(), "3");
+    assert_eq!(engine::resolve("-7 // 2").unwrap(), "-4");
+    assert_eq!(engine::resolve_as::<Integer>("-7 // 2").unwrap(), "-4");
+    assert_eq!(engine::resolve_as::<Decimal>("7.5 // 2").unwrap(), "3");
+    assert_eq!(engine::resolve_as::<Rational>("7/2 // (1/3)").unwrap(), "10");
+}
+
+#[test]
+fn test_remainder_has_multiplicative_precedence() {
+    assert_eq!(engine::resolve("1 + 7 % 4 * 2").unwrap(), "7");
+    assert_eq!(engine::resolve("2 ^ 3 // 3").unwrap(), "2");
+}
+
+#[test]
+fn test_remainder_by_zero() {
+    assert_eq!(engine::resolve("7 % 0"), Err(Error::DivisionByZero(Span::new(4, 5))));
+    assert_eq!(engine::resolve_as::<Integer>("7 // 0"), Err(Error::DivisionByZero(Span::new(5, 6))));
+}
@@ -1,11 +1,15 @@
 use rustcalculator::calculator;
 use rustcalculator::calculator::{Token, Operator};
 
+mod common;
+
+use common::values;
+
 #[test]
 fn test_parser() {
     let tokens_result = crate::calculator::Calculator::parse("1 + 2 * (3 - 4)");
 
-    let tokens = tokens_result.unwrap();
+    let tokens = values(tokens_result.unwrap());
 
     let expected_tokens = vec![
         Token::Number(1.0), Token::Operator(Operator::Add),
@@ -21,7 +25,7 @@ fn test_parser() {
 fn test_parse_decimal() {
     let tokens_result = crate::calculator::Calculator::parse("2.5");
 
-    let tokens = tokens_result.unwrap();
+    let tokens = values(tokens_result.unwrap());
 
     let expected_tokens = vec![
         Token::Number(2.5)
@@ -36,7 +40,7 @@ fn test_parse_decimal() {
 fn test_parse_with_decimals() {
     let tokens_result = crate::calculator::Calculator::parse("2.5 + 3.5");
 
-    let tokens = tokens_result.unwrap();
+    let tokens = values(tokens_result.unwrap());
 
     let expected_tokens = vec![
         Token::Number(2.5), Token::Operator(Operator::Add), Token::Number(3.5)
@@ -63,7 +67,7 @@ fn test_parse_with_decimals() {
 fn test_expression_queue() {
     let tokens_result = calculator::Calculator::parse("1 + 2 * (3 - 4)");
 
-    let expression_queue = calculator::Calculator::expression(tokens_result.unwrap());
+    let expression_queue = values(calculator::Calculator::expression(tokens_result.unwrap()));
 
     let expected_queue: Vec<Token> = vec![
         Token::Number(1.0), Token::Number(2.0), Token::Number(3.0), Token::Number(4.0),
@@ -95,7 +99,7 @@ fn test_expression_queue() {
 fn test_second_expression_queue() {
     let tokens_result = calculator::Calculator::parse("(5 * 3) + (8 - 2) / 4");
 
-    let expression_queue = calculator::Calculator::expression(tokens_result.unwrap());
+    let expression_queue = values(calculator::Calculator::expression(tokens_result.unwrap()));
 
     let expected_queue: Vec<Token> = vec![
         Token::Number(5.0), Token::Number(3.0), Token::Operator(Operator::Mul), Token::Number(8.0), Token::Number(2.0),
@@ -126,7 +130,7 @@ fn test_second_expression_queue() {
 fn test_third_expression_queue() {
     let tokens_result = calculator::Calculator::parse("4 + 4 * 2 / ( 1 - 5 )");
 
-    let expression_queue = calculator::Calculator::expression(tokens_result.unwrap());
+    let expression_queue = values(calculator::Calculator::expression(tokens_result.unwrap()));
 
     let expected_queue: Vec<Token> = vec![
         Token::Number(4.0), Token::Number(4.0), Token::Number(2.0), Token::Operator(Operator::Mul), Token::Number(1.0),
@@ -0,0 +1,61 @@
+use rustcalculator::engine;
+use rustcalculator::engine::{Error, Operator, Span, Token};
+
+mod common;
+
+use common::values;
+
+#[test]
+fn test_unary_minus_is_told_apart_from_subtraction() {
+    let tokens = values(engine::tokenize("-3 - -2").unwrap());
+
+    let expected_tokens = vec![
+        Token::Operator(Operator::Neg), Token::Number(3.0),
+        Token::Operator(Operator::Sub),
+        Token::Operator(Operator::Neg), Token::Number(2.0)
+    ];
+
+    assert_eq!(tokens, expected_tokens)
+}
+
+// EXPRESSION: 2 * -4 + 1
+// TOKEN | ACTION                        | QUEUE         | STACK
+// 2     | Add to queue                  | 2             |
+// *     | Add to stack                  | 2             | *
+// -     | Prefix, add to stack          | 2             | - *
+// 4     | Add to queue                  | 2 4           | - *
+// +     | Pop - and * to queue, push +  | 2 4 - *       | +
+// 1     | Add to queue                  | 2 4 - * 1     | +
+// end   | Pop entire stack to output    | 2 4 - * 1 +   |
+#[test]
+fn test_unary_minus_queue() {
+    let queue = values(engine::to_rpn(engine::tokenize("2 * -4 + 1").unwrap()));
+
+    let expected_queue = vec![
+        Token::Number(2.0), Token::Number(4.0), Token::Operator(Operator::Neg),
+        Token::Operator(Operator::Mul), Token::Number(1.0), Token::Operator(Operator::Add)
+    ];
+
+    assert_eq!(queue, expected_queue)
+}
+
+#[test]
+fn test_unary_expressions() {
+    assert_eq!(engine::resolve("-3 + 5").unwrap(), "2");
+    assert_eq!(engine::resolve("2 * -4").unwrap(), "-8");
+    assert_eq!(engine::resolve("(-1)").unwrap(), "-1");
+    assert_eq!(engine::resolve("--3").unwrap(), "3");
+    assert_eq!(engine::resolve("+3 - +1").unwrap(), "2");
+    assert_eq!(engine::resolve("-(2 + 3) * 2").unwrap(), "-10");
+}
+
+#[test]
+fn test_unary_minus_on_exact_backends() {
+    assert_eq!(engine::resolve_as::<engine::rational::Rational>("-1/2 + 1").unwrap(), "1/2");
+    assert_eq!(engine::resolve_as::<engine::decimal::Decimal>("-0.1 - 0.2").unwrap(), "-0.3");
+}
+
+#[test]
+fn test_trailing_unary_minus() {
+    assert_eq!(engine::resolve("2 * -"), Err(Error::TrailingOperator(Operator::Neg, Span::new(4, 5))))
+}
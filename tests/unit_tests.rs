@@ -0,0 +1,80 @@
+use rustcalculator::engine;
+use rustcalculator::engine::environment::{Environment, Outcome};
+use rustcalculator::engine::rational::{FractionStyle, Rational, RationalContext};
+use rustcalculator::engine::units::{Quantity, Unit};
+use rustcalculator::engine::{Error, Operator, Settings, Span, Value};
+
+#[test]
+fn test_quantities() {
+    assert_eq!(engine::resolve("3 ft").unwrap(), "3 ft");
+    assert_eq!(engine::resolve("-2.5 km").unwrap(), "-2.5 km");
+    assert_eq!(engine::resolve("2 m * 3 m").unwrap(), "6 m^2");
+    assert_eq!(engine::resolve("(3 s)^-2").unwrap(), "0.1111111111111111 s^-2");
+    assert_eq!(engine::resolve("12 m / (4 s)").unwrap(), "3 m/s");
+    assert_eq!(engine::resolve("100 km / 2 h").unwrap(), "50 km/h");
+    assert_eq!(engine::resolve("1 m / 1 s").unwrap(), "1 m/s");
+    assert_eq!(engine::resolve("1 / 2 s").unwrap(), "0.5 s^-1");
+    assert_eq!(engine::resolve("1 kg*m/s^2").unwrap(), "1 kg*m/s^2");
+    assert_eq!(engine::resolve("1 km / (1 m)").unwrap(), "1000");
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(engine::resolve("3 ft + 20 cm in m").unwrap(), "1.1144 m");
+    assert_eq!(engine::resolve("1 mi to ft").unwrap(), "5280 ft");
+    assert_eq!(engine::resolve("5 to 1").unwrap(), "5");
+    assert_eq!(engine::resolve("1 ms to s").unwrap(), "0.001 s");
+    assert_eq!(engine::resolve("5 m^2 to cm^2").unwrap(), "50000 cm^2");
+    assert_eq!(engine::resolve("1 kg*m/s^2 to N").unwrap(), "1 N");
+    assert_eq!(engine::resolve("2 h + 30 min to min").unwrap(), "150 min");
+
+    let settings = Settings::<Rational>::new(RationalContext { style: FractionStyle::Decimal, ..Default::default() });
+    assert_eq!(engine::resolve_with("60 mph to km/h", &settings).unwrap(), "96.56064 km/h");
+    assert_eq!(engine::resolve_as::<Rational>("1 m in inch").unwrap(), "5000/127 inch");
+}
+
+#[test]
+fn test_comparing_quantities() {
+    assert_eq!(engine::resolve("1 ft < 1 m").unwrap(), "true");
+    assert_eq!(engine::resolve("1 m == 100 cm").unwrap(), "true");
+    assert_eq!(engine::resolve("1 lb > 1 kg").unwrap(), "false");
+}
+
+#[test]
+fn test_units_in_environment() {
+    let mut environment = Environment::<f64>::default();
+    environment.set("m", 2.0);
+
+    assert_eq!(environment.resolve("3 m").unwrap(), "6");
+    assert_eq!(environment.resolve("5 cm").unwrap(), "5 cm");
+    assert_eq!(environment.answer(1), Some(&Value::Number(6.0)));
+    assert_eq!(environment.resolve("4 / 2 m").unwrap(), "4");
+    assert_eq!(
+        environment.execute("1 s"),
+        Ok(Outcome::Quantity(Quantity::new(1.0, Unit::from_name("s", &()).unwrap())))
+    );
+    assert_eq!(environment.resolve("d = 3 ft").unwrap(), "3 ft");
+    assert_eq!(environment.resolve("d + 1 ft").unwrap(), "4 ft");
+    assert_eq!(environment.resolve("ans * 2").unwrap(), "8 ft");
+    assert_eq!(environment.compute("x = 1 s"), Err(Error::UnexpectedUnit(Span::new(0, 7))));
+    assert_eq!(environment.compute("1 s"), Err(Error::UnexpectedUnit(Span::new(0, 3))));
+    assert!(matches!(environment.evaluate("2 s"), Ok(Value::Quantity(_))));
+}
+
+#[test]
+fn test_unit_errors() {
+    assert_eq!(engine::resolve("1 m + 1 s"), Err(Error::IncompatibleUnits(Span::new(0, 9))));
+    assert_eq!(engine::resolve("1 kg to m"), Err(Error::IncompatibleUnits(Span::new(0, 9))));
+    assert_eq!(engine::resolve("5 km + 3"), Err(Error::IncompatibleUnits(Span::new(0, 8))));
+    assert_eq!(engine::resolve("sqrt(4 m)"), Err(Error::UnexpectedUnit(Span::new(5, 8))));
+    assert_eq!(engine::resolve("2 ^ (1 s)"), Err(Error::UnexpectedUnit(Span::new(5, 8))));
+    assert_eq!(engine::resolve("2 m ^ 0.5"), Err(Error::NotAnInteger(Span::new(6, 9))));
+    assert_eq!(engine::resolve("1 m / (0 s)"), Err(Error::DivisionByZero(Span::new(7, 10))));
+    assert_eq!(engine::resolve("60 mph to 2 km/h"), Err(Error::ExpectedUnit(Span::new(10, 16))));
+    assert_eq!(engine::resolve("1 m to 100 cm"), Err(Error::ExpectedUnit(Span::new(7, 13))));
+    assert_eq!(engine::resolve("1 m ^ 2147483647 * 1 m"), Err(Error::Overflow(Span::new(0, 22))));
+    assert_eq!(engine::resolve("((1 m)^65536)^65536"), Err(Error::Overflow(Span::new(2, 19))));
+    assert_eq!(engine::resolve("(1 m)^-2147483648").unwrap(), "1 m^-2147483648");
+    assert_eq!(engine::resolve("to m"), Err(Error::MissingOperand(Operator::To, Span::new(0, 2))));
+    assert_eq!(engine::resolve("1 furlong"), Err(Error::UnknownIdentifier("furlong".to_owned(), Span::new(2, 9))));
+}
@@ -0,0 +1,80 @@
+use rustcalculator::engine::environment::Outcome;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Environment, Error, Span};
+
+#[test]
+fn test_user_function_definition() {
+    let mut environment = Environment::<f64>::default();
+
+    assert_eq!(environment.execute("f(x, y) = x^2 + y"), Ok(Outcome::Definition("f".to_owned())));
+    assert_eq!(environment.resolve("f(3, 4)").unwrap(), "13");
+    assert_eq!(environment.resolve("f(1, 1) * f(2, 0)").unwrap(), "8");
+    assert_eq!(environment.resolve("answer() = 42").unwrap(), "answer() = 42");
+    assert_eq!(environment.resolve("answer() / 2").unwrap(), "21");
+
+    let definitions: Vec<String> = environment.functions().map(|function| function.to_string()).collect();
+    assert_eq!(definitions, vec!["answer() = 42", "f(x, y) = x^2 + y"]);
+
+    assert!(environment.remove_function("f").is_some());
+    assert_eq!(environment.resolve("f(3, 4)"), Err(Error::UnknownFunction("f".to_owned(), Span::new(0, 7))));
+}
+
+#[test]
+fn test_user_function_scoping() {
+    let mut environment = Environment::<Rational>::default();
+
+    environment.resolve("x = 10").unwrap();
+    environment.resolve("half(x) = x / 2").unwrap();
+    environment.resolve("shift(y) = y + x").unwrap();
+    environment.resolve("both(x) = half(x) + shift(0)").unwrap();
+
+    assert_eq!(environment.resolve("half(3)").unwrap(), "3/2");
+    assert_eq!(environment.resolve("shift(1)").unwrap(), "11");
+    assert_eq!(environment.resolve("both(1)").unwrap(), "21/2");
+    assert_eq!(environment.resolve("x").unwrap(), "10");
+
+    environment.resolve("rate(s) = 6 / 2 s").unwrap();
+    assert_eq!(environment.resolve("rate(5)").unwrap(), "15");
+}
+
+#[test]
+fn test_user_function_errors() {
+    let mut environment = Environment::<f64>::default();
+
+    environment.resolve("f(x) = 1 / x").unwrap();
+    environment.resolve("g(x) = g(x)").unwrap();
+
+    assert_eq!(environment.resolve("f(1, 2)"), Err(Error::WrongArgumentCount("f".to_owned(), 2, Span::new(0, 7))));
+    assert_eq!(
+        environment.resolve("2 + f(0)"),
+        Err(Error::InFunction("f".to_owned(), Box::new(Error::DivisionByZero(Span::new(11, 12))), Span::new(4, 8)))
+    );
+    assert_eq!(environment.resolve("g(1)"), Err(Error::RecursionLimit("g".to_owned(), Span::new(0, 4))));
+
+    assert_eq!(environment.resolve("sqrt(x) = x"), Err(Error::InvalidAssignment(Span::new(0, 4))));
+    assert_eq!(environment.resolve("pi(x) = 2"), Err(Error::InvalidAssignment(Span::new(0, 2))));
+    assert_eq!(environment.resolve("e(x) = x"), Err(Error::InvalidAssignment(Span::new(0, 1))));
+    assert_eq!(environment.resolve("true(x) = 1"), Err(Error::InvalidAssignment(Span::new(0, 4))));
+    assert_eq!(environment.resolve("ans(x) = 1"), Err(Error::InvalidAssignment(Span::new(0, 3))));
+    assert_eq!(environment.resolve("h(x, x) = x"), Err(Error::InvalidAssignment(Span::new(5, 6))));
+    assert_eq!(environment.resolve("h(x + 1) = x"), Err(Error::InvalidAssignment(Span::new(9, 10))));
+    assert_eq!(environment.compute("h(x) = x"), Err(Error::InvalidAssignment(Span::new(5, 6))));
+}
+
+#[test]
+fn test_user_function_arguments() {
+    let mut environment = Environment::<f64>::default();
+
+    environment.resolve("double(x) = 2 x").unwrap();
+    environment.resolve("speed(d, t) = d / t").unwrap();
+    environment.resolve("pick(c, a, b) = if(c, a, b)").unwrap();
+
+    assert_eq!(environment.resolve("double(1 m)").unwrap(), "2 m");
+    assert_eq!(environment.resolve("speed(100 km, 2 h)").unwrap(), "50 km/h");
+    assert_eq!(environment.resolve("pick(true, 1, 2)").unwrap(), "1");
+    assert_eq!(environment.resolve("pick(1 < 0, 1 ft, 2 ft)").unwrap(), "2 ft");
+    assert_eq!(
+        environment.resolve("double(true)"),
+        Err(Error::InFunction("double".to_owned(), Box::new(Error::ExpectedNumber(Span::new(14, 15))), Span::new(0, 12)))
+    );
+}
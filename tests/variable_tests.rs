@@ -0,0 +1,48 @@
+use rustcalculator::engine;
+use rustcalculator::engine::rational::Rational;
+use rustcalculator::engine::{Environment, Error, Span, Token, Value};
+
+#[test]
+fn test_assignment_tokens() {
+    let tokens: Vec<Token> = engine::tokenize("rate = 0.07").unwrap().into_iter().map(|token| token.value).collect();
+
+    assert_eq!(tokens, vec![Token::Identifier("rate".to_owned()), Token::Assign, Token::Number(0.07)]);
+}
+
+#[test]
+fn test_variables_persist() {
+    let mut environment = Environment::<f64>::default();
+
+    assert_eq!(environment.resolve("rate = 0.25").unwrap(), "0.25");
+    assert_eq!(environment.resolve("1200 * rate").unwrap(), "300");
+    assert_eq!(environment.resolve("rate = rate * 2").unwrap(), "0.5");
+    assert_eq!(environment.get("rate"), Some(&Value::Number(0.5)));
+    assert_eq!(environment.resolve("total_2 = 3").unwrap(), "3");
+
+    let names: Vec<&str> = environment.variables().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["rate", "total_2"]);
+
+    assert_eq!(environment.remove("rate"), Some(Value::Number(0.5)));
+    assert_eq!(environment.resolve("rate"), Err(Error::UnknownIdentifier("rate".to_owned(), Span::new(0, 4))));
+}
+
+#[test]
+fn test_variables_exact_backend() {
+    let mut environment = Environment::<Rational>::default();
+
+    environment.resolve("third = 1/3").unwrap();
+    assert_eq!(environment.resolve("third + 1/6").unwrap(), "1/2");
+}
+
+#[test]
+fn test_assignment_errors() {
+    let mut environment = Environment::<f64>::default();
+
+    assert_eq!(environment.resolve("x + 1"), Err(Error::UnknownIdentifier("x".to_owned(), Span::new(0, 1))));
+    assert_eq!(environment.resolve("pi = 3"), Err(Error::InvalidAssignment(Span::new(0, 2))));
+    assert_eq!(environment.resolve("sqrt = 2"), Err(Error::InvalidAssignment(Span::new(0, 4))));
+    assert_eq!(environment.resolve("2 = 3"), Err(Error::InvalidAssignment(Span::new(2, 3))));
+    assert_eq!(environment.resolve("x = y = 3"), Err(Error::InvalidAssignment(Span::new(6, 7))));
+    assert_eq!(environment.resolve("x ="), Err(Error::EmptyExpression(Span::new(2, 3))));
+    assert_eq!(environment.variables().count(), 0);
+}
@@ -0,0 +1,60 @@
+use rustcalculator::engine;
+use rustcalculator::engine::word::{Radix, Word, WordContext, WordSize};
+use rustcalculator::engine::{Error, Settings, Span};
+
+fn resolve(expr: &str, size: WordSize, signed: bool) -> Result<String, Error> {
+    engine::resolve_with::<Word>(expr, &Settings::new(WordContext { size, signed }))
+}
+
+#[test]
+fn test_word_wraps_around() {
+    assert_eq!(resolve("127 + 1", WordSize::Bits8, true).unwrap(), "-128");
+    assert_eq!(resolve("255 + 1", WordSize::Bits8, false).unwrap(), "0");
+    assert_eq!(resolve("0 - 1", WordSize::Bits16, false).unwrap(), "65535");
+    assert_eq!(resolve("0xFFFFFFFF", WordSize::Bits32, true).unwrap(), "-1");
+    assert_eq!(resolve("1 << 63", WordSize::Bits64, true).unwrap(), "-9223372036854775808");
+    assert_eq!(resolve("1 << 63", WordSize::Bits64, false).unwrap(), "9223372036854775808");
+    assert_eq!(resolve("2 ^ 64 + 5", WordSize::Bits64, false).unwrap(), "5");
+    assert_eq!(resolve("-128 / -1", WordSize::Bits8, true).unwrap(), "-128");
+}
+
+#[test]
+fn test_word_integer_arithmetic() {
+    assert_eq!(resolve("7 / 2", WordSize::Bits32, true).unwrap(), "3");
+    assert_eq!(resolve("-7 / 2", WordSize::Bits32, true).unwrap(), "-3");
+    assert_eq!(resolve("-7 // 2", WordSize::Bits32, true).unwrap(), "-4");
+    assert_eq!(resolve("-7 % 3", WordSize::Bits32, true).unwrap(), "-1");
+    assert_eq!(resolve("~0", WordSize::Bits16, false).unwrap(), "65535");
+    assert_eq!(resolve("0xF0 >> 4", WordSize::Bits8, false).unwrap(), "15");
+    assert_eq!(resolve("sqrt(10)", WordSize::Bits32, true).unwrap(), "3");
+    assert_eq!(resolve("2 ^ -1", WordSize::Bits32, true).unwrap(), "0");
+}
+
+#[test]
+fn test_word_errors() {
+    assert_eq!(resolve("1.5 + 1", WordSize::Bits32, true), Err(Error::InvalidNumber(Span::new(0, 3))));
+    assert_eq!(resolve("1 / 0", WordSize::Bits32, true), Err(Error::DivisionByZero(Span::new(4, 5))));
+    assert_eq!(resolve(".", WordSize::Bits32, true), Err(Error::InvalidNumber(Span::new(0, 1))));
+}
+
+#[test]
+fn test_word_radixes() {
+    let context = WordContext { size: WordSize::Bits8, signed: true };
+    let value = engine::compute_with::<Word>("-6", &Settings::new(context)).unwrap();
+
+    let radixes: Vec<String> = Radix::ALL.iter().map(|radix| value.to_radix(*radix, &context)).collect();
+
+    assert_eq!(radixes, vec!["0xFA", "-6", "0o372", "0b11111010"])
+}
+
+#[test]
+fn test_word_bits() {
+    let context = WordContext { size: WordSize::Bits16, signed: true };
+    let value = Word::new(5, &context);
+
+    assert!(value.bit(2, &context));
+    assert!(!value.bit(1, &context));
+    assert_eq!(value.toggle_bit(1, &context).value(), 7);
+    assert_eq!(value.toggle_bit(15, &context).value(), -32763);
+    assert_eq!(Word::new(-1, &context).pattern(&context), 0xFFFF);
+}